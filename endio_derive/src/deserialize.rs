@@ -33,12 +33,12 @@ pub fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 
 	// todo[hygiene]: replace __ENDIO_ENDIANNESS, __ENDIO_READER with unique ident
 	input.generics.params.push(parse_quote!(__ENDIO_ENDIANNESS: ::endio::Endianness));
-	input.generics.params.push(parse_quote!(__ENDIO_READER: ::std::io::Read + ::endio::ERead<__ENDIO_ENDIANNESS>));
+	input.generics.params.push(parse_quote!(__ENDIO_READER: ::endio::ByteRead + ::endio::ERead<__ENDIO_ENDIANNESS>));
 	let (impl_generics,	_, _) = input.generics.split_for_impl();
 
 	let expanded = quote! {
 		impl #impl_generics ::endio::Deserialize<__ENDIO_ENDIANNESS, __ENDIO_READER> for #name #ty_generics #where_clause {
-			fn deserialize(reader: &mut __ENDIO_READER) -> ::std::io::Result<Self> {
+			fn deserialize(reader: &mut __ENDIO_READER) -> ::core::result::Result<Self, ::endio::Error> {
 				#deser_code
 				#read_padding
 				Ok(ret)
@@ -142,7 +142,7 @@ fn gen_deser_code_enum(data: &DataEnum, name: &Ident, ty: &Ident, pre_disc_paddi
 		#read_post_padding
 		let ret = match disc {
 			#(#arms)*
-			_ => return ::std::result::Result::Err(::std::io::Error::new(::std::io::ErrorKind::InvalidData, format!("invalid discriminant value for {}: {}", stringify!(#name), disc)))
+			_ => return ::core::result::Result::Err(::endio::Error::new(::endio::ErrorKind::InvalidData, ::endio::__private::format!("invalid discriminant value for {}: {}", stringify!(#name), disc)))
 		};
 	}
 }
@@ -151,7 +151,7 @@ fn gen_read_padding(padding: &Option<LitInt>) -> TokenStream {
 	match padding {
 		Some(x) => quote! {
 			let mut padding = [0; #x];
-			::std::io::Read::read_exact(reader, &mut padding)?;
+			::endio::ByteRead::read_exact(reader, &mut padding)?;
 		},
 		None => quote! { },
 	}
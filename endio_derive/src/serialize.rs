@@ -35,12 +35,12 @@ pub fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 	// todo[hygiene]: replace __ENDIO_LIFETIME, __ENDIO_ENDIANNESS, __ENDIO_WRITER with unique ident
 	input.generics.params.push(parse_quote!('__ENDIO_LIFETIME));
 	input.generics.params.push(parse_quote!(__ENDIO_ENDIANNESS: ::endio::Endianness));
-	input.generics.params.push(parse_quote!(__ENDIO_WRITER: ::std::io::Write + ::endio::EWrite<__ENDIO_ENDIANNESS>));
+	input.generics.params.push(parse_quote!(__ENDIO_WRITER: ::endio::ByteWrite + ::endio::EWrite<__ENDIO_ENDIANNESS>));
 	let (impl_generics,	_, _) = input.generics.split_for_impl();
 
 	let expanded = quote! {
 		impl #impl_generics ::endio::Serialize<__ENDIO_ENDIANNESS, __ENDIO_WRITER> for &'__ENDIO_LIFETIME #name #ty_generics #where_clause {
-			fn serialize(self, writer: &mut __ENDIO_WRITER) -> ::std::io::Result<()> {
+			fn serialize(self, writer: &mut __ENDIO_WRITER) -> ::core::result::Result<(), ::endio::Error> {
 				#ser_code
 				#write_padding
 				Ok(())
@@ -156,7 +156,7 @@ fn gen_write_padding(padding: &Option<LitInt>) -> TokenStream {
 	match padding {
 		Some(x) => quote! {
 			let mut padding = [0; #x];
-			::std::io::Write::write_all(writer, &padding)?;
+			::endio::ByteWrite::write_all(writer, &padding)?;
 		},
 		None => quote! { },
 	}
@@ -0,0 +1,228 @@
+use core::convert::TryFrom;
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+
+use crate::io;
+use crate::io::Result as Res;
+use crate::{Deserialize, Endianness, ERead, EWrite, Serialize};
+
+/**
+	A bool stored as an integer of type `T`, with configurable values for `true` and `false`.
+
+	Plain `bool`s are (de-)serialized as a single byte that is either 0 or 1. This wrapper uses `T` in the stream's endianness instead, with the value `TRUE` for `true` and `FALSE` for `false`. The values need to be non-negative, so for example a 16-bit bool with `-1` as `true` needs to use `u16` and `0xffff`.
+
+	Reading returns an `InvalidData` error if the value is neither `TRUE` nor `FALSE`. Writing returns an `InvalidInput` error if the value doesn't fit in `T`. See [`LenientBool`] for treating any nonzero value as `true`.
+
+	## Examples
+
+	```
+	use endio::{Bool, LERead, LEWrite, Win32Bool};
+
+	let mut reader = &b"\x01\x00\x00\x00"[..];
+	let val: Win32Bool = reader.read().unwrap();
+	assert!(*val);
+
+	let mut writer = vec![];
+	writer.write(Bool::<u8, 0xff>::new(true)).unwrap();
+	assert_eq!(writer, b"\xff");
+	```
+*/
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Bool<T = u8, const TRUE: u128 = 1, const FALSE: u128 = 0> {
+	inner: bool,
+	int_type: PhantomData<T>,
+}
+
+/**
+	A bool stored as an integer of type `T`, where any nonzero value is `true`.
+
+	Reading returns `false` if the value is zero and `true` otherwise, so this never fails on valid integers. Writing uses 0 for `false` and `TRUE` for `true`, and returns an `InvalidInput` error if `TRUE` doesn't fit in `T`.
+
+	## Examples
+
+	```
+	use endio::{BERead, BEWrite, LenientBool};
+
+	let mut reader = &b"\x00\x2a"[..];
+	let val: LenientBool<u16> = reader.read().unwrap();
+	assert!(*val);
+
+	let mut writer = vec![];
+	writer.write(val).unwrap();
+	assert_eq!(writer, b"\x00\x01");
+	```
+*/
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct LenientBool<T = u8, const TRUE: u128 = 1> {
+	inner: bool,
+	int_type: PhantomData<T>,
+}
+
+/// The Windows API's `BOOL`, a 4-byte integer that is 1 for `true` and 0 for `false`.
+pub type Win32Bool = Bool<i32>;
+
+fn value_to_int<T: TryFrom<u128>>(value: u128) -> Res<T> {
+	T::try_from(value).map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "bool value does not fit in integer type"))
+}
+
+macro_rules! impl_bool {
+	($t:ident, $($consts:ident),*) => {
+		impl<T, $(const $consts: u128),*> $t<T, $($consts),*> {
+			/// Wraps the bool.
+			pub fn new(inner: bool) -> Self {
+				Self { inner, int_type: PhantomData }
+			}
+
+			/// Unwraps the bool.
+			pub fn into_inner(self) -> bool {
+				self.inner
+			}
+		}
+
+		impl<T, $(const $consts: u128),*> From<bool> for $t<T, $($consts),*> {
+			fn from(inner: bool) -> Self {
+				Self::new(inner)
+			}
+		}
+
+		impl<T, $(const $consts: u128),*> From<$t<T, $($consts),*>> for bool {
+			fn from(value: $t<T, $($consts),*>) -> Self {
+				value.inner
+			}
+		}
+
+		impl<T, $(const $consts: u128),*> Deref for $t<T, $($consts),*> {
+			type Target = bool;
+
+			fn deref(&self) -> &bool {
+				&self.inner
+			}
+		}
+
+		impl<T, $(const $consts: u128),*> DerefMut for $t<T, $($consts),*> {
+			fn deref_mut(&mut self) -> &mut bool {
+				&mut self.inner
+			}
+		}
+
+		impl<E: Endianness, W: EWrite<E>, T, $(const $consts: u128),*> Serialize<E, W> for &$t<T, $($consts),*> where $t<T, $($consts),*>: Serialize<E, W> + Copy {
+			fn serialize(self, writer: &mut W) -> Res<()> {
+				writer.write(*self)
+			}
+		}
+	}
+}
+
+impl_bool!(Bool, TRUE, FALSE);
+impl_bool!(LenientBool, TRUE);
+
+/// Reads a `T`, returning an `InvalidData` error if it is neither `TRUE` nor `FALSE`.
+impl<E: Endianness, R: ERead<E>, T, const TRUE: u128, const FALSE: u128> Deserialize<E, R> for Bool<T, TRUE, FALSE>
+	where T: Deserialize<E, R> + TryFrom<u128> + PartialEq {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let value: T = reader.read()?;
+		if T::try_from(TRUE).is_ok_and(|x| value == x) {
+			Ok(Self::new(true))
+		} else if T::try_from(FALSE).is_ok_and(|x| value == x) {
+			Ok(Self::new(false))
+		} else {
+			Err(io::Error::new(io::ErrorKind::InvalidData, "Bool had value other than the true or false value"))
+		}
+	}
+}
+
+/// Writes `TRUE` or `FALSE` as a `T`.
+impl<E: Endianness, W: EWrite<E>, T, const TRUE: u128, const FALSE: u128> Serialize<E, W> for Bool<T, TRUE, FALSE>
+	where T: Serialize<E, W> + TryFrom<u128> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		writer.write(value_to_int::<T>(if self.inner { TRUE } else { FALSE })?)
+	}
+}
+
+/// Reads a `T`, which is `true` if it is nonzero.
+impl<E: Endianness, R: ERead<E>, T, const TRUE: u128> Deserialize<E, R> for LenientBool<T, TRUE>
+	where T: Deserialize<E, R> + Default + PartialEq {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let value: T = reader.read()?;
+		Ok(Self::new(value != T::default()))
+	}
+}
+
+/// Writes `TRUE` or 0 as a `T`.
+impl<E: Endianness, W: EWrite<E>, T, const TRUE: u128> Serialize<E, W> for LenientBool<T, TRUE>
+	where T: Serialize<E, W> + TryFrom<u128> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		writer.write(value_to_int::<T>(if self.inner { TRUE } else { 0 })?)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::io;
+	use crate::{Bool, LenientBool, Win32Bool};
+
+	#[test]
+	fn read_bool() {
+		let data = b"\x00\x00\x00\x01\x00\x00\x00\x00";
+		use crate::BERead;
+		let mut reader = &data[..];
+		let val: Win32Bool = reader.read().unwrap();
+		assert!(*val);
+		let val: Win32Bool = reader.read().unwrap();
+		assert!(!*val);
+	}
+
+	#[test]
+	fn read_bool_custom_values() {
+		let data = b"\xff\xff\x00\x00";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let val: Bool<u16, 0xffff> = reader.read().unwrap();
+		assert!(*val);
+		let val: Bool<u16, 0xffff> = reader.read().unwrap();
+		assert!(!*val);
+	}
+
+	#[test]
+	fn read_bool_invalid() {
+		let data = b"\x02";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let err = reader.read::<Bool>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+	}
+
+	#[test]
+	fn read_lenient_bool() {
+		let data = b"\x00\x01\xff";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let val: LenientBool = reader.read().unwrap();
+		assert!(!*val);
+		let val: LenientBool = reader.read().unwrap();
+		assert!(*val);
+		let val: LenientBool = reader.read().unwrap();
+		assert!(*val);
+	}
+
+	#[test]
+	fn write_bool() {
+		let data = b"\x00\x00\x00\x01\x00\x00\x00\x00\xff\x00";
+		use crate::BEWrite;
+		let mut writer = vec![];
+		writer.write(Win32Bool::new(true)).unwrap();
+		writer.write(&[Win32Bool::new(false)][..]).unwrap();
+		writer.write(LenientBool::<u8, 0xff>::new(true)).unwrap();
+		writer.write(LenientBool::<u8, 0xff>::new(false)).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn write_bool_value_too_large() {
+		use crate::LEWrite;
+		let mut writer = vec![];
+		let err = writer.write(Bool::<u8, 256>::new(true)).unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+		assert!(writer.is_empty());
+	}
+}
@@ -0,0 +1,351 @@
+use alloc::collections::{BinaryHeap, BTreeMap, BTreeSet, VecDeque};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+#[cfg(feature="std")]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature="std")]
+use std::collections::{HashMap, HashSet};
+
+use crate::io;
+use crate::io::Result as Res;
+use crate::{Deserialize, Endianness, ERead, EWrite, LenPrefixed, Serialize};
+use crate::len_prefixed::{read_len, write_len};
+#[cfg(feature="std")]
+use crate::len_prefixed::MAX_PREALLOC;
+
+/**
+	Decides what happens when a map or set is read that contains the same key more than once.
+
+	Use one of the provided policies [`RejectDuplicates`], [`KeepFirst`] or [`KeepLast`] as the `D` parameter of [`LenPrefixed`].
+
+	## Examples
+
+	```
+	use std::collections::BTreeMap;
+	use endio::{KeepLast, LERead, LenPrefixed};
+
+	let mut reader = &b"\x02\x01\x2a\x01\x2b"[..];
+	assert!(reader.read::<LenPrefixed<u8, BTreeMap<u8, u8>>>().is_err());
+
+	let mut reader = &b"\x02\x01\x2a\x01\x2b"[..];
+	let val: LenPrefixed<u8, BTreeMap<u8, u8>, KeepLast> = reader.read().unwrap();
+	assert_eq!(val[&1], 0x2b);
+	```
+*/
+pub trait DuplicatePolicy {
+	/// Returns whether the new entry should replace the existing one, or an error if duplicates aren't allowed.
+	fn replace_existing() -> Res<bool>;
+}
+
+/// Returns an `InvalidData` error when reading a duplicate key. This is the default.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RejectDuplicates;
+
+/// Keeps the first entry when reading a duplicate key, and ignores the later ones.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct KeepFirst;
+
+/// Replaces the previous entry when reading a duplicate key, so that the last one is kept.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct KeepLast;
+
+impl DuplicatePolicy for RejectDuplicates {
+	fn replace_existing() -> Res<bool> {
+		Err(io::Error::new(io::ErrorKind::InvalidData, "collection contained duplicate key"))
+	}
+}
+
+impl DuplicatePolicy for KeepFirst {
+	fn replace_existing() -> Res<bool> {
+		Ok(false)
+	}
+}
+
+impl DuplicatePolicy for KeepLast {
+	fn replace_existing() -> Res<bool> {
+		Ok(true)
+	}
+}
+
+/// Reads a count of type `L`, then reads that many key/value pairs.
+#[cfg(feature="std")]
+impl<E: Endianness, R: ERead<E>, L, K, V, S, D> Deserialize<E, R> for LenPrefixed<L, HashMap<K, V, S>, D>
+	where L: Deserialize<E, R>,
+	      usize: TryFrom<L>,
+	      K: Deserialize<E, R> + Eq + Hash,
+	      V: Deserialize<E, R>,
+	      S: BuildHasher + Default,
+	      D: DuplicatePolicy {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let len = read_len::<E, R, L>(reader)?;
+		let mut map = HashMap::with_capacity_and_hasher(len.min(MAX_PREALLOC), S::default());
+		for _ in 0..len {
+			let key = reader.read()?;
+			let value = reader.read()?;
+			if !map.contains_key(&key) || D::replace_existing()? {
+				map.insert(key, value);
+			}
+		}
+		Ok(Self::new(map))
+	}
+}
+
+/// Reads a count of type `L`, then reads that many key/value pairs.
+impl<E: Endianness, R: ERead<E>, L, K, V, D> Deserialize<E, R> for LenPrefixed<L, BTreeMap<K, V>, D>
+	where L: Deserialize<E, R>,
+	      usize: TryFrom<L>,
+	      K: Deserialize<E, R> + Ord,
+	      V: Deserialize<E, R>,
+	      D: DuplicatePolicy {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let len = read_len::<E, R, L>(reader)?;
+		let mut map = BTreeMap::new();
+		for _ in 0..len {
+			let key = reader.read()?;
+			let value = reader.read()?;
+			if !map.contains_key(&key) || D::replace_existing()? {
+				map.insert(key, value);
+			}
+		}
+		Ok(Self::new(map))
+	}
+}
+
+/// Reads a count of type `L`, then reads that many keys.
+#[cfg(feature="std")]
+impl<E: Endianness, R: ERead<E>, L, K, S, D> Deserialize<E, R> for LenPrefixed<L, HashSet<K, S>, D>
+	where L: Deserialize<E, R>,
+	      usize: TryFrom<L>,
+	      K: Deserialize<E, R> + Eq + Hash,
+	      S: BuildHasher + Default,
+	      D: DuplicatePolicy {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let len = read_len::<E, R, L>(reader)?;
+		let mut set = HashSet::with_capacity_and_hasher(len.min(MAX_PREALLOC), S::default());
+		for _ in 0..len {
+			let key = reader.read()?;
+			if !set.contains(&key) || D::replace_existing()? {
+				set.replace(key);
+			}
+		}
+		Ok(Self::new(set))
+	}
+}
+
+/// Reads a count of type `L`, then reads that many keys.
+impl<E: Endianness, R: ERead<E>, L, K, D> Deserialize<E, R> for LenPrefixed<L, BTreeSet<K>, D>
+	where L: Deserialize<E, R>,
+	      usize: TryFrom<L>,
+	      K: Deserialize<E, R> + Ord,
+	      D: DuplicatePolicy {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let len = read_len::<E, R, L>(reader)?;
+		let mut set = BTreeSet::new();
+		for _ in 0..len {
+			let key = reader.read()?;
+			if !set.contains(&key) || D::replace_existing()? {
+				set.replace(key);
+			}
+		}
+		Ok(Self::new(set))
+	}
+}
+
+/// Reads a length of type `L`, then reads that many elements.
+impl<E: Endianness, R: ERead<E>, L, T> Deserialize<E, R> for LenPrefixed<L, VecDeque<T>>
+	where L: Deserialize<E, R>,
+	      usize: TryFrom<L>,
+	      T: Deserialize<E, R> {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let vec: LenPrefixed<L, Vec<T>> = reader.read()?;
+		Ok(Self::new(vec.into_inner().into()))
+	}
+}
+
+/// Reads a length of type `L`, then reads that many elements.
+impl<E: Endianness, R: ERead<E>, L, T> Deserialize<E, R> for LenPrefixed<L, BinaryHeap<T>>
+	where L: Deserialize<E, R>,
+	      usize: TryFrom<L>,
+	      T: Deserialize<E, R> + Ord {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let vec: LenPrefixed<L, Vec<T>> = reader.read()?;
+		Ok(Self::new(vec.into_inner().into()))
+	}
+}
+
+/// Writes the number of entries as `L`, then writes the key/value pairs.
+#[cfg(feature="std")]
+impl<E: Endianness, W: EWrite<E>, L, K, V, S, D> Serialize<E, W> for &LenPrefixed<L, HashMap<K, V, S>, D>
+	where L: Serialize<E, W> + TryFrom<usize>,
+	      for<'a> &'a K: Serialize<E, W>,
+	      for<'a> &'a V: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		write_len::<E, W, L>(writer, self.len())?;
+		for (key, value) in self.iter() {
+			writer.write(key)?;
+			writer.write(value)?;
+		}
+		Ok(())
+	}
+}
+
+/// Writes the number of entries as `L`, then writes the key/value pairs in order.
+impl<E: Endianness, W: EWrite<E>, L, K, V, D> Serialize<E, W> for &LenPrefixed<L, BTreeMap<K, V>, D>
+	where L: Serialize<E, W> + TryFrom<usize>,
+	      for<'a> &'a K: Serialize<E, W>,
+	      for<'a> &'a V: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		write_len::<E, W, L>(writer, self.len())?;
+		for (key, value) in self.iter() {
+			writer.write(key)?;
+			writer.write(value)?;
+		}
+		Ok(())
+	}
+}
+
+macro_rules! impl_ser_seq {
+	($t:ty $(, $param:ident)*) => {
+		/// Writes the number of elements as `L`, then writes the elements in iteration order.
+		impl<E: Endianness, W: EWrite<E>, L, T, $($param),*> Serialize<E, W> for &$t
+			where L: Serialize<E, W> + TryFrom<usize>,
+			      for<'a> &'a T: Serialize<E, W> {
+			fn serialize(self, writer: &mut W) -> Res<()> {
+				write_len::<E, W, L>(writer, self.len())?;
+				for elem in self.iter() {
+					writer.write(elem)?;
+				}
+				Ok(())
+			}
+		}
+	}
+}
+
+#[cfg(feature="std")]
+impl_ser_seq!(LenPrefixed<L, HashSet<T, S>, D>, S, D);
+impl_ser_seq!(LenPrefixed<L, BTreeSet<T>, D>, D);
+impl_ser_seq!(LenPrefixed<L, VecDeque<T>>);
+impl_ser_seq!(LenPrefixed<L, BinaryHeap<T>>);
+
+#[cfg(test)]
+mod tests {
+	use std::collections::{BinaryHeap, BTreeMap, BTreeSet, VecDeque};
+	#[cfg(feature="std")]
+	use std::collections::{HashMap, HashSet};
+	use crate::io;
+	use crate::LenPrefixed;
+	#[cfg(feature="std")]
+	use crate::{KeepFirst, KeepLast};
+
+	#[test]
+	#[cfg(feature="std")]
+	fn read_hash_map() {
+		let data = b"\x00\x02\x01\xba\xad\x02\xf0\x0d";
+		use crate::BERead;
+		let mut reader = &data[..];
+		let val: LenPrefixed<u16, HashMap<u8, u16>> = reader.read().unwrap();
+		assert_eq!(*val, [(1, 0xbaad), (2, 0xf00d)].iter().copied().collect());
+	}
+
+	#[test]
+	#[cfg(feature="std")]
+	fn read_map_duplicates() {
+		let data = b"\x03\x01\x0a\x02\x0b\x01\x0c";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let err = reader.read::<LenPrefixed<u8, BTreeMap<u8, u8>>>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+		let mut reader = &data[..];
+		let val: LenPrefixed<u8, BTreeMap<u8, u8>, KeepFirst> = reader.read().unwrap();
+		assert_eq!(val.into_inner().into_iter().collect::<Vec<_>>(), vec![(1, 0x0a), (2, 0x0b)]);
+		let mut reader = &data[..];
+		let val: LenPrefixed<u8, HashMap<u8, u8>, KeepLast> = reader.read().unwrap();
+		assert_eq!(val[&1], 0x0c);
+		assert_eq!(val[&2], 0x0b);
+		assert!(reader.is_empty());
+	}
+
+	#[test]
+	#[cfg(feature="std")]
+	fn read_set_duplicates() {
+		let data = b"\x03\x01\x02\x01";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let err = reader.read::<LenPrefixed<u8, HashSet<u8>>>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+		let mut reader = &data[..];
+		let val: LenPrefixed<u8, BTreeSet<u8>, KeepFirst> = reader.read().unwrap();
+		assert_eq!(val.iter().copied().collect::<Vec<_>>(), vec![1, 2]);
+	}
+
+	#[test]
+	fn read_vec_deque() {
+		let data = b"\x02\x00\x00\x00\xba\xad";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let val: LenPrefixed<u32, VecDeque<u8>> = reader.read().unwrap();
+		assert_eq!(*val, vec![0xba, 0xad]);
+	}
+
+	#[test]
+	fn read_binary_heap() {
+		let data = b"\x03\x01\x03\x02";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let val: LenPrefixed<u8, BinaryHeap<u8>> = reader.read().unwrap();
+		assert_eq!(val.into_inner().into_sorted_vec(), vec![1, 2, 3]);
+	}
+
+	#[test]
+	fn write_btree_map() {
+		let data = b"\x02\x00\x01\xad\xba\x02\x0d\xf0";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		let map: BTreeMap<u8, u16> = [(2, 0xf00d), (1, 0xbaad)].iter().copied().collect();
+		writer.write(&LenPrefixed::<u16, _>::new(map)).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	#[cfg(feature="std")]
+	fn write_hash_map() {
+		let data = b"\x01\x01\xba\xad";
+		use crate::BEWrite;
+		let mut writer = vec![];
+		let map: HashMap<u8, u16> = [(1, 0xbaad)].iter().copied().collect();
+		writer.write(&LenPrefixed::<u8, _>::new(map)).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	#[cfg(feature="std")]
+	fn write_sets() {
+		let data = b"\x02\x01\x02\x01\x2a";
+		use crate::BEWrite;
+		let mut writer = vec![];
+		let set: BTreeSet<u8> = [2, 1].iter().copied().collect();
+		writer.write(&LenPrefixed::<u8, _>::new(set)).unwrap();
+		let set: HashSet<u8> = [0x2a].iter().copied().collect();
+		writer.write(&LenPrefixed::<u8, _>::new(set)).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn write_vec_deque() {
+		let data = b"\x02\xba\xad";
+		use crate::BEWrite;
+		let mut writer = vec![];
+		let deque: VecDeque<u8> = vec![0xba, 0xad].into();
+		writer.write(&LenPrefixed::<u8, _>::new(deque)).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn write_len_too_large() {
+		use crate::LEWrite;
+		let mut writer = vec![];
+		let set: BTreeSet<u16> = (0..256).collect();
+		let err = writer.write(&LenPrefixed::<u8, _>::new(set)).unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+	}
+}
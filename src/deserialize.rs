@@ -1,10 +1,26 @@
-use std::io;
-use std::io::Read;
-use std::io::Result as Res;
-use std::mem::size_of;
-use std::net::Ipv4Addr;
-
-use crate::{BigEndian, ERead, Endianness, LittleEndian};
+use alloc::borrow::{Cow, ToOwned};
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+use core::convert::TryFrom;
+use core::marker::PhantomData;
+use core::mem::{size_of, size_of_val, MaybeUninit};
+use core::num::{NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128};
+use core::num::Wrapping;
+use core::ops::{Range, RangeInclusive};
+use core::ptr;
+use core::time::Duration;
+#[cfg(feature="std")]
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+#[cfg(not(feature="std"))]
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+use crate::io;
+use crate::io::Result as Res;
+use crate::{BigEndian, ByteRead, Endianness, ERead, LittleEndian};
+use crate::len_prefixed::{read_bytes, split_bytes, MAX_PREALLOC};
 
 /**
 	Implement this for your types to be able to `read` them.
@@ -192,6 +208,29 @@ use crate::{BigEndian, ERead, Endianness, LittleEndian};
 	# }
 	```
 
+	### Borrowing from the input
+
+	When reading from a byte slice, some types can borrow from the input instead of copying: `&[u8; N]`, [`LenPrefixed<L, &[u8]>`](crate::LenPrefixed) and [`LenString<L, &str>`](crate::LenString). Structs and enums with lifetime parameters can contain these fields and still be derived, and will then only be readable from a `&[u8]`.
+
+	```
+	# #[cfg(feature="derive")] {
+	# use endio::{Deserialize, LenPrefixed, LenString};
+	#[derive(Deserialize)]
+	struct Example<'a> {
+		magic: &'a [u8; 4],
+		name: LenString<u8, &'a str>,
+		data: LenPrefixed<u16, &'a [u8]>,
+	}
+	use endio::LERead;
+	let input = b"RIFF\x05hello\x02\x00\xba\xad";
+	let mut reader = &input[..];
+	let val: Example = reader.read().unwrap();
+	assert_eq!(val.magic, b"RIFF");
+	assert_eq!(*val.name, "hello");
+	assert_eq!(*val.data, b"\xba\xad");
+	# }
+	```
+
 	## Custom deserializations
 
 	If your deserialization is complex or has special cases, you'll need to implement `Deserialize` manually.
@@ -206,7 +245,7 @@ use crate::{BigEndian, ERead, Endianness, LittleEndian};
 
 	- Rust currently can't recognize sealed traits. Even though there are only two endiannesses, and the primitive types are implemented for them, the compiler can't recognize that. If/When the compiler gets smarter about sealed traits this will be resolved. Alternatively, once Rust gets support for specialization, I will be able to add a dummy blanket `impl` to primitives which will work around this issue.
 
-	- The underlying `R` type needs to implement `std::io::Read` to be able to read into primitive types. You can work around this by explicitly specifying `Read` as trait bound, but since both `Read` and `ERead` have a `read` method, Rust will force you to use UFCS syntax to disambiguate between them. This makes using `read` less ergonomic, and I personally think that `where` clauses are the better alternative here, since they avoid this issue.
+	- The underlying `R` type needs to implement `ByteRead` (which every `std::io::Read` does) to be able to read into primitive types. You can work around this by explicitly specifying `Read` as trait bound, but since both `Read` and `ERead` have a `read` method, Rust will force you to use UFCS syntax to disambiguate between them. This makes using `read` less ergonomic, and I personally think that `where` clauses are the better alternative here, since they avoid this issue.
 
 		Ideally I'd like to make `std::io::Read` a supertrait of `ERead`, since the deserialization will normally depend on `Read` anyway. Unfortunately, supertraits' methods automatically get brought into scope, so this would mean that you would be forced to use UFCS every time, without being able to work around them with `where` clauses. ([Rust issue #17151](https://github.com/rust-lang/rust/issues/17151)).
 	```
@@ -216,14 +255,13 @@ use crate::{BigEndian, ERead, Endianness, LittleEndian};
 		c: u32,
 	}
 	# {
-	use std::io::Result;
-	use endio::{Deserialize, Endianness, ERead};
+	use endio::{Deserialize, Endianness, ERead, Error};
 
 	impl<E: Endianness, R: ERead<E>> Deserialize<E, R> for Example
 		where bool: Deserialize<E, R>,
 		      u8  : Deserialize<E, R>,
 		      u32 : Deserialize<E, R> {
-		fn deserialize(reader: &mut R) -> Result<Self> {
+		fn deserialize(reader: &mut R) -> Result<Self, Error> {
 			let a = reader.read()?;
 			let b = reader.read()?;
 			let c = reader.read()?;
@@ -246,18 +284,17 @@ use crate::{BigEndian, ERead, Endianness, LittleEndian};
 	# }
 	```
 
-	### Deserialize a primitive / something where you need the bare `std::io::Read` functionality:
+	### Deserialize a primitive / something where you need the bare `ByteRead` functionality:
 
-	Note how the trait bound for `R` is `Read`.
+	Note how the trait bound for `R` is `ByteRead`. With the `std` feature, you can also use `std::io::Read` and its other methods.
 
 	```
-	use std::io::{Read, Result};
-	use endio::{Deserialize, Endianness, ERead};
+	use endio::{ByteRead, Deserialize, Endianness, ERead, Error};
 
 	struct new_u8(u8);
 
-	impl<E: Endianness, R: Read> Deserialize<E, R> for new_u8 {
-		fn deserialize(reader: &mut R) -> Result<Self> {
+	impl<E: Endianness, R: ByteRead> Deserialize<E, R> for new_u8 {
+		fn deserialize(reader: &mut R) -> Result<Self, Error> {
 			let mut buf = [0; 1];
 			reader.read_exact(&mut buf);
 			Ok(new_u8(buf[0]))
@@ -269,22 +306,21 @@ use crate::{BigEndian, ERead, Endianness, LittleEndian};
 
 	Note how instead of using a trait bound on Endianness, we implement Deserialize twice, once for `BigEndian` and once for `LittleEndian`.
 	```
-	use std::io::{Read, Result};
 	use std::mem::size_of;
-	use endio::{BigEndian, Deserialize, LittleEndian};
+	use endio::{BigEndian, ByteRead, Deserialize, Error, LittleEndian};
 
 	struct new_u16(u16);
 
-	impl<R: Read> Deserialize<BigEndian, R> for new_u16 {
-		fn deserialize(reader: &mut R) -> Result<Self> {
+	impl<R: ByteRead> Deserialize<BigEndian, R> for new_u16 {
+		fn deserialize(reader: &mut R) -> Result<Self, Error> {
 			let mut buf = [0; size_of::<u16>()];
 			reader.read_exact(&mut buf)?;
 			Ok(new_u16(u16::from_be_bytes(buf)))
 		}
 	}
 
-	impl<R: Read> Deserialize<LittleEndian, R> for new_u16 {
-		fn deserialize(reader: &mut R) -> Result<Self> {
+	impl<R: ByteRead> Deserialize<LittleEndian, R> for new_u16 {
+		fn deserialize(reader: &mut R) -> Result<Self, Error> {
 			let mut buf = [0; size_of::<u16>()];
 			reader.read_exact(&mut buf)?;
 			Ok(new_u16(u16::from_le_bytes(buf)))
@@ -295,10 +331,75 @@ use crate::{BigEndian, ERead, Endianness, LittleEndian};
 pub trait Deserialize<E: Endianness, R>: Sized {
 	/// Deserializes the type by reading from the reader.
 	fn deserialize(reader: &mut R) -> Res<Self>;
+
+	/**
+		Deserializes an array of `N` values by reading from the reader.
+
+		Only for internal use: This is overridden by types that can read consecutive values faster than one by one. There's no need to implement this yourself.
+	*/
+	#[doc(hidden)]
+	fn deserialize_array<const N: usize>(reader: &mut R) -> Res<[Self; N]> {
+		// an array of MaybeUninit doesn't require initialization
+		let mut array: [MaybeUninit<Self>; N] = unsafe { MaybeUninit::uninit().assume_init() };
+		let mut guard = ArrayGuard { array: &mut array, initialized: 0 };
+		while guard.initialized < N {
+			guard.array[guard.initialized] = MaybeUninit::new(Self::deserialize(reader)?);
+			guard.initialized += 1;
+		}
+		core::mem::forget(guard);
+		// all elements have been initialized, and MaybeUninit<T> has the same layout as T
+		Ok(unsafe { ptr::read(&array as *const [MaybeUninit<Self>; N] as *const [Self; N]) })
+	}
+
+	/**
+		Deserializes `len` values into a `Vec` by reading from the reader.
+
+		Only for internal use: This is overridden by types that can read consecutive values faster than one by one. There's no need to implement this yourself.
+	*/
+	#[doc(hidden)]
+	fn deserialize_vec(reader: &mut R, len: usize) -> Res<Vec<Self>> {
+		let mut vec = Vec::with_capacity(len.min(MAX_PREALLOC));
+		for _ in 0..len {
+			vec.push(Self::deserialize(reader)?);
+		}
+		Ok(vec)
+	}
+}
+
+/// Views a slice of primitive numbers as its bytes in native endianness.
+///
+/// Safety: Every byte pattern must be a valid `T`, which is the case for integers and floats.
+unsafe fn as_bytes_mut<T>(slice: &mut [T]) -> &mut [u8] {
+	core::slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut u8, size_of_val(slice))
+}
+
+/// Reads `len` integers in native endianness, using a single read if `len` is at most `MAX_PREALLOC`. Larger lengths aren't trusted for preallocation, instead the Vec is grown geometrically as data arrives.
+fn read_int_vec<R: ByteRead, T: Copy + Default>(reader: &mut R, len: usize) -> Res<Vec<T>> {
+	let mut vec = Vec::new();
+	while vec.len() < len {
+		let start = vec.len();
+		vec.resize(start + (len - start).min(start.max(MAX_PREALLOC)), T::default());
+		reader.read_exact(unsafe { as_bytes_mut(&mut vec[start..]) })?;
+	}
+	Ok(vec)
+}
+
+/// Drops the already initialized elements of a partially deserialized array if deserializing one of the later elements fails.
+struct ArrayGuard<'a, T> {
+	array: &'a mut [MaybeUninit<T>],
+	initialized: usize,
+}
+
+impl<T> Drop for ArrayGuard<'_, T> {
+	fn drop(&mut self) {
+		for elem in &mut self.array[..self.initialized] {
+			unsafe { ptr::drop_in_place(elem.as_mut_ptr()); }
+		}
+	}
 }
 
 /// Reads a bool by reading a byte, returning false for 0, true for 1, and an `InvalidData` error for any other value.
-impl<E: Endianness, R: Read> Deserialize<E, R> for bool {
+impl<E: Endianness, R: ByteRead> Deserialize<E, R> for bool {
 	fn deserialize(reader: &mut R) -> Res<Self> {
 		let mut buf = [0; size_of::<Self>()];
 		reader.read_exact(&mut buf)?;
@@ -310,38 +411,86 @@ impl<E: Endianness, R: Read> Deserialize<E, R> for bool {
 	}
 }
 
-impl<E: Endianness, R: Read> Deserialize<E, R> for i8 {
+impl<E: Endianness, R: ByteRead> Deserialize<E, R> for i8 {
 	fn deserialize(reader: &mut R) -> Res<Self> {
 		let mut buf = [0; size_of::<Self>()];
 		reader.read_exact(&mut buf)?;
 		Ok(Self::from_ne_bytes(buf))
 	}
+
+	fn deserialize_array<const N: usize>(reader: &mut R) -> Res<[Self; N]> {
+		let mut buf = [0; N];
+		reader.read_exact(&mut buf)?;
+		Ok(buf.map(|x| x as Self))
+	}
+
+	fn deserialize_vec(reader: &mut R, len: usize) -> Res<Vec<Self>> {
+		Ok(read_bytes(reader, len)?.into_iter().map(|x| x as Self).collect())
+	}
 }
 
-impl<E: Endianness, R: Read> Deserialize<E, R> for u8 {
+impl<E: Endianness, R: ByteRead> Deserialize<E, R> for u8 {
 	fn deserialize(reader: &mut R) -> Res<Self> {
 		let mut buf = [0; size_of::<Self>()];
 		reader.read_exact(&mut buf)?;
 		Ok(Self::from_ne_bytes(buf))
 	}
+
+	fn deserialize_array<const N: usize>(reader: &mut R) -> Res<[Self; N]> {
+		let mut buf = [0; N];
+		reader.read_exact(&mut buf)?;
+		Ok(buf)
+	}
+
+	fn deserialize_vec(reader: &mut R, len: usize) -> Res<Vec<Self>> {
+		read_bytes(reader, len)
+	}
 }
 
 macro_rules! impl_int {
 	($t:ident) => {
-		impl<R: Read> Deserialize<BigEndian, R> for $t {
+		impl<R: ByteRead> Deserialize<BigEndian, R> for $t {
 			fn deserialize(reader: &mut R) -> Res<Self> {
 				let mut buf = [0; size_of::<Self>()];
 				reader.read_exact(&mut buf)?;
 				Ok(Self::from_be_bytes(buf))
 			}
+
+			fn deserialize_array<const N: usize>(reader: &mut R) -> Res<[Self; N]> {
+				let mut array = [0; N];
+				reader.read_exact(unsafe { as_bytes_mut(&mut array) })?;
+				Ok(array.map(Self::from_be))
+			}
+
+			fn deserialize_vec(reader: &mut R, len: usize) -> Res<Vec<Self>> {
+				let mut vec = read_int_vec::<R, Self>(reader, len)?;
+				for x in &mut vec {
+					*x = Self::from_be(*x);
+				}
+				Ok(vec)
+			}
 		}
 
-		impl<R: Read> Deserialize<LittleEndian, R> for $t {
+		impl<R: ByteRead> Deserialize<LittleEndian, R> for $t {
 			fn deserialize(reader: &mut R) -> Res<Self> {
 				let mut buf = [0; size_of::<Self>()];
 				reader.read_exact(&mut buf)?;
 				Ok(Self::from_le_bytes(buf))
 			}
+
+			fn deserialize_array<const N: usize>(reader: &mut R) -> Res<[Self; N]> {
+				let mut array = [0; N];
+				reader.read_exact(unsafe { as_bytes_mut(&mut array) })?;
+				Ok(array.map(Self::from_le))
+			}
+
+			fn deserialize_vec(reader: &mut R, len: usize) -> Res<Vec<Self>> {
+				let mut vec = read_int_vec::<R, Self>(reader, len)?;
+				for x in &mut vec {
+					*x = Self::from_le(*x);
+				}
+				Ok(vec)
+			}
 		}
 
 		#[cfg(test)]
@@ -382,6 +531,14 @@ impl<E: Endianness, R: ERead<E>> Deserialize<E, R> for f32 where u32: Deserializ
 		let ival: u32 = reader.read()?;
 		Ok(Self::from_bits(ival))
 	}
+
+	fn deserialize_array<const N: usize>(reader: &mut R) -> Res<[Self; N]> {
+		Ok(u32::deserialize_array(reader)?.map(Self::from_bits))
+	}
+
+	fn deserialize_vec(reader: &mut R, len: usize) -> Res<Vec<Self>> {
+		Ok(u32::deserialize_vec(reader, len)?.into_iter().map(Self::from_bits).collect())
+	}
 }
 
 impl<E: Endianness, R: ERead<E>> Deserialize<E, R> for f64 where u64: Deserialize<E, R> {
@@ -389,9 +546,48 @@ impl<E: Endianness, R: ERead<E>> Deserialize<E, R> for f64 where u64: Deserializ
 		let ival: u64 = reader.read()?;
 		Ok(Self::from_bits(ival))
 	}
+
+	fn deserialize_array<const N: usize>(reader: &mut R) -> Res<[Self; N]> {
+		Ok(u64::deserialize_array(reader)?.map(Self::from_bits))
+	}
+
+	fn deserialize_vec(reader: &mut R, len: usize) -> Res<Vec<Self>> {
+		Ok(u64::deserialize_vec(reader, len)?.into_iter().map(Self::from_bits).collect())
+	}
 }
 
-impl<E: Endianness, R: Read> Deserialize<E, R> for Ipv4Addr {
+macro_rules! impl_nonzero {
+	($t:ident, $int:ident) => {
+		/// Reads the underlying integer, returning an `InvalidData` error if it is zero.
+		impl<E: Endianness, R: ERead<E>> Deserialize<E, R> for $t where $int: Deserialize<E, R> {
+			fn deserialize(reader: &mut R) -> Res<Self> {
+				let ival: $int = reader.read()?;
+				Self::new(ival).ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, concat!(stringify!($t), " had value 0")))
+			}
+		}
+	}
+}
+
+impl_nonzero!(NonZeroU8, u8);
+impl_nonzero!(NonZeroU16, u16);
+impl_nonzero!(NonZeroU32, u32);
+impl_nonzero!(NonZeroU64, u64);
+impl_nonzero!(NonZeroU128, u128);
+impl_nonzero!(NonZeroI8, i8);
+impl_nonzero!(NonZeroI16, i16);
+impl_nonzero!(NonZeroI32, i32);
+impl_nonzero!(NonZeroI64, i64);
+impl_nonzero!(NonZeroI128, i128);
+
+/// Reads a char by reading a `u32`, returning an `InvalidData` error if it is not a Unicode scalar value.
+impl<E: Endianness, R: ERead<E>> Deserialize<E, R> for char where u32: Deserialize<E, R> {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let ival: u32 = reader.read()?;
+		core::char::from_u32(ival).ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "char had value that is not a Unicode scalar value"))
+	}
+}
+
+impl<E: Endianness, R: ByteRead> Deserialize<E, R> for Ipv4Addr {
 	fn deserialize(reader: &mut R) -> Res<Self> {
 		let mut buf = [0; 4];
 		reader.read_exact(&mut buf)?;
@@ -399,6 +595,120 @@ impl<E: Endianness, R: Read> Deserialize<E, R> for Ipv4Addr {
 	}
 }
 
+impl<E: Endianness, R: ByteRead> Deserialize<E, R> for Ipv6Addr {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let mut buf = [0; 16];
+		reader.read_exact(&mut buf)?;
+		Ok(buf.into())
+	}
+}
+
+/// Reads an `IpAddr` by reading a `u8` tag of 4 or 6, followed by the address. Returns an `InvalidData` error for any other tag.
+impl<E: Endianness, R: ERead<E>> Deserialize<E, R> for IpAddr
+	where u8: Deserialize<E, R>,
+	      Ipv4Addr: Deserialize<E, R>,
+	      Ipv6Addr: Deserialize<E, R> {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let tag: u8 = reader.read()?;
+		match tag {
+			4 => Ok(IpAddr::V4(reader.read()?)),
+			6 => Ok(IpAddr::V6(reader.read()?)),
+			_ => Err(io::Error::new(io::ErrorKind::InvalidData, "IpAddr had tag other than 4 or 6")),
+		}
+	}
+}
+
+/// Reads a `SocketAddrV4` by reading the address, followed by the port.
+impl<E: Endianness, R: ERead<E>> Deserialize<E, R> for SocketAddrV4
+	where u16: Deserialize<E, R>,
+	      Ipv4Addr: Deserialize<E, R> {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let ip = reader.read()?;
+		let port = reader.read()?;
+		Ok(Self::new(ip, port))
+	}
+}
+
+/// Reads a `SocketAddrV6` by reading the address, followed by the port, flowinfo and scope id.
+impl<E: Endianness, R: ERead<E>> Deserialize<E, R> for SocketAddrV6
+	where u16: Deserialize<E, R>,
+	      u32: Deserialize<E, R>,
+	      Ipv6Addr: Deserialize<E, R> {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let ip = reader.read()?;
+		let port = reader.read()?;
+		let flowinfo = reader.read()?;
+		let scope_id = reader.read()?;
+		Ok(Self::new(ip, port, flowinfo, scope_id))
+	}
+}
+
+/// Reads a `SocketAddr` by reading a `u8` tag of 4 or 6, followed by the socket address. Returns an `InvalidData` error for any other tag.
+impl<E: Endianness, R: ERead<E>> Deserialize<E, R> for SocketAddr
+	where u8: Deserialize<E, R>,
+	      SocketAddrV4: Deserialize<E, R>,
+	      SocketAddrV6: Deserialize<E, R> {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let tag: u8 = reader.read()?;
+		match tag {
+			4 => Ok(SocketAddr::V4(reader.read()?)),
+			6 => Ok(SocketAddr::V6(reader.read()?)),
+			_ => Err(io::Error::new(io::ErrorKind::InvalidData, "SocketAddr had tag other than 4 or 6")),
+		}
+	}
+}
+
+/// Reads `N` values of `T` in order.
+impl<E: Endianness, R, T: Deserialize<E, R>, const N: usize> Deserialize<E, R> for [T; N] {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		T::deserialize_array(reader)
+	}
+}
+
+/// Borrows `N` bytes from the input without copying them.
+impl<'a, E: Endianness, const N: usize> Deserialize<E, &'a [u8]> for &'a [u8; N] {
+	fn deserialize(reader: &mut &'a [u8]) -> Res<Self> {
+		let bytes = split_bytes(reader, N)?;
+		Ok(<&[u8; N]>::try_from(bytes).unwrap())
+	}
+}
+
+/// Reads a `Result<T, F>` by reading a bool, and if it is `true`, reads `T`, otherwise reads `F`.
+impl<E: Endianness, R: ERead<E>, T: Deserialize<E, R>, F: Deserialize<E, R>> Deserialize<E, R> for Result<T, F> where bool: Deserialize<E, R> {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let is_ok: bool = reader.read()?;
+		Ok(if is_ok {
+			Ok(reader.read()?)
+		} else {
+			Err(reader.read()?)
+		})
+	}
+}
+
+macro_rules! impl_tuple {
+	($($t:ident)+) => {
+		/// Reads the elements of the tuple in order.
+		impl<E: Endianness, R: ERead<E>, $($t: Deserialize<E, R>),+> Deserialize<E, R> for ($($t,)+) {
+			fn deserialize(reader: &mut R) -> Res<Self> {
+				Ok(($(reader.read::<$t>()?,)+))
+			}
+		}
+	}
+}
+
+impl_tuple!(A);
+impl_tuple!(A B);
+impl_tuple!(A B C);
+impl_tuple!(A B C D);
+impl_tuple!(A B C D F);
+impl_tuple!(A B C D F G);
+impl_tuple!(A B C D F G H);
+impl_tuple!(A B C D F G H I);
+impl_tuple!(A B C D F G H I J);
+impl_tuple!(A B C D F G H I J K);
+impl_tuple!(A B C D F G H I J K L);
+impl_tuple!(A B C D F G H I J K L M);
+
 /// Reads an `Option<T>` by reading a bool, and if it is `true`, reads `T`.
 impl<E: Endianness, R: ERead<E>, T: Deserialize<E, R>> Deserialize<E, R> for Option<T> where bool: Deserialize<E, R> {
 	fn deserialize(reader: &mut R) -> Res<Self> {
@@ -411,10 +721,78 @@ impl<E: Endianness, R: ERead<E>, T: Deserialize<E, R>> Deserialize<E, R> for Opt
 	}
 }
 
+/// Reads nothing.
+impl<E: Endianness, R> Deserialize<E, R> for () {
+	fn deserialize(_reader: &mut R) -> Res<Self> {
+		Ok(())
+	}
+}
+
+/// Reads nothing.
+impl<E: Endianness, R, T> Deserialize<E, R> for PhantomData<T> {
+	fn deserialize(_reader: &mut R) -> Res<Self> {
+		Ok(PhantomData)
+	}
+}
+
+macro_rules! impl_wrapper {
+	($t:ident, $new:expr) => {
+		/// Reads the wrapped value.
+		impl<E: Endianness, R, T: Deserialize<E, R>> Deserialize<E, R> for $t<T> {
+			fn deserialize(reader: &mut R) -> Res<Self> {
+				Ok($new(T::deserialize(reader)?))
+			}
+		}
+	}
+}
+
+impl_wrapper!(Box, Box::new);
+impl_wrapper!(Rc, Rc::new);
+impl_wrapper!(Arc, Arc::new);
+impl_wrapper!(Wrapping, Wrapping);
+impl_wrapper!(Reverse, Reverse);
+
+/// Reads the owned value, and always returns `Cow::Owned`.
+impl<E: Endianness, R, T: ToOwned + ?Sized> Deserialize<E, R> for Cow<'_, T> where T::Owned: Deserialize<E, R> {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		Ok(Cow::Owned(T::Owned::deserialize(reader)?))
+	}
+}
+
+/// Reads a `Range<T>` by reading the start, followed by the end.
+impl<E: Endianness, R: ERead<E>, T: Deserialize<E, R>> Deserialize<E, R> for Range<T> {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let start = reader.read()?;
+		let end = reader.read()?;
+		Ok(start..end)
+	}
+}
+
+/// Reads a `RangeInclusive<T>` by reading the start, followed by the end.
+impl<E: Endianness, R: ERead<E>, T: Deserialize<E, R>> Deserialize<E, R> for RangeInclusive<T> {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let start = reader.read()?;
+		let end = reader.read()?;
+		Ok(start..=end)
+	}
+}
+
+/// Reads a `Duration` by reading the seconds as a `u64`, followed by the nanoseconds as a `u32`. Returns an `InvalidData` error if the nanoseconds are a whole second or more.
+impl<E: Endianness, R: ERead<E>> Deserialize<E, R> for Duration where u64: Deserialize<E, R>, u32: Deserialize<E, R> {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let secs = reader.read()?;
+		let nanos = reader.read()?;
+		if nanos >= 1_000_000_000 {
+			return Err(io::Error::new(io::ErrorKind::InvalidData, "Duration had nanoseconds of a second or more"));
+		}
+		Ok(Self::new(secs, nanos))
+	}
+}
+
 #[cfg(test)]
 mod tests {
-	use std::io;
-	use std::io::Result as Res;
+	use crate::io;
+	use crate::io::Result as Res;
 
 	#[test]
 	fn read_bool_false() {
@@ -542,6 +920,77 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn read_nonzero() {
+		use std::num::NonZeroU32;
+
+		let data = b"\xba\xad\xf0\x0d";
+		let mut val: NonZeroU32;
+		{
+			use crate::BERead;
+			let mut reader = &data[..];
+			val = reader.read().unwrap();
+			assert_eq!(val.get(), 0xbaadf00d);
+		}
+		{
+			use crate::LERead;
+			let mut reader = &data[..];
+			val = reader.read().unwrap();
+			assert_eq!(val.get(), 0x0df0adba);
+		}
+	}
+
+	#[test]
+	fn read_nonzero_signed() {
+		use std::num::NonZeroI8;
+
+		let data = b"\x80";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let val: NonZeroI8 = reader.read().unwrap();
+		assert_eq!(val.get(), i8::MIN);
+	}
+
+	#[test]
+	fn read_nonzero_zero() {
+		use std::num::NonZeroU16;
+
+		let data = b"\x00\x00";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let val = reader.read::<NonZeroU16>().unwrap_err();
+		assert_eq!(val.kind(), io::ErrorKind::InvalidData);
+		assert_eq!(val.to_string(), "NonZeroU16 had value 0");
+	}
+
+	#[test]
+	fn read_char() {
+		let mut val: char;
+		{
+			use crate::BERead;
+			let mut reader = &b"\x00\x01\xf6\x00"[..];
+			val = reader.read().unwrap();
+			assert_eq!(val, '\u{1f600}');
+		}
+		{
+			use crate::LERead;
+			let mut reader = &b"\x00\xf6\x01\x00"[..];
+			val = reader.read().unwrap();
+			assert_eq!(val, '\u{1f600}');
+		}
+	}
+
+	#[test]
+	fn read_char_invalid() {
+		let data = b"\x00\xd8\x00\x00\x00\x00\x11\x00";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let val = reader.read::<char>().unwrap_err();
+		assert_eq!(val.kind(), io::ErrorKind::InvalidData);
+		let val = reader.read::<char>().unwrap_err();
+		assert_eq!(val.kind(), io::ErrorKind::InvalidData);
+	}
+
 	#[test]
 	fn read_ipv4_addr() {
 		use std::net::Ipv4Addr;
@@ -562,6 +1011,196 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn read_ipv6_addr() {
+		use std::net::Ipv6Addr;
+
+		let data = b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01";
+		let mut val: Ipv6Addr;
+		{
+			use crate::BERead;
+			let mut reader = &data[..];
+			val = reader.read().unwrap();
+			assert_eq!(val, Ipv6Addr::LOCALHOST);
+		}
+		{
+			use crate::LERead;
+			let mut reader = &data[..];
+			val = reader.read().unwrap();
+			assert_eq!(val, Ipv6Addr::LOCALHOST);
+		}
+	}
+
+	#[test]
+	fn read_ip_addr() {
+		use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+		let data = b"\x04\x7f\x00\x00\x01\x06\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let val: IpAddr = reader.read().unwrap();
+		assert_eq!(val, IpAddr::V4(Ipv4Addr::LOCALHOST));
+		let val: IpAddr = reader.read().unwrap();
+		assert_eq!(val, IpAddr::V6(Ipv6Addr::LOCALHOST));
+	}
+
+	#[test]
+	fn read_ip_addr_invalid() {
+		use std::net::IpAddr;
+
+		let data = b"\x05\x7f\x00\x00\x01";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let val = reader.read::<IpAddr>().unwrap_err();
+		assert_eq!(val.kind(), io::ErrorKind::InvalidData);
+	}
+
+	#[test]
+	fn read_socket_addr_v4() {
+		use std::net::{Ipv4Addr, SocketAddrV4};
+
+		let data = b"\x7f\x00\x00\x01\x1f\x90";
+		let mut val: SocketAddrV4;
+		{
+			use crate::BERead;
+			let mut reader = &data[..];
+			val = reader.read().unwrap();
+			assert_eq!(val, SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0x1f90));
+		}
+		{
+			use crate::LERead;
+			let mut reader = &data[..];
+			val = reader.read().unwrap();
+			assert_eq!(val, SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0x901f));
+		}
+	}
+
+	#[test]
+	fn read_socket_addr_v6() {
+		use std::net::{Ipv6Addr, SocketAddrV6};
+
+		let data = b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01\x90\x1f\x01\x00\x00\x00\x02\x00\x00\x00";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let val: SocketAddrV6 = reader.read().unwrap();
+		assert_eq!(val, SocketAddrV6::new(Ipv6Addr::LOCALHOST, 0x1f90, 1, 2));
+	}
+
+	#[test]
+	fn read_socket_addr() {
+		use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+
+		let data = b"\x04\x7f\x00\x00\x01\x1f\x90";
+		use crate::BERead;
+		let mut reader = &data[..];
+		let val: SocketAddr = reader.read().unwrap();
+		assert_eq!(val, SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0x1f90)));
+	}
+
+	#[test]
+	fn read_array() {
+		let data = b"\xba\xad\xf0\x0d";
+		let mut val: [u16; 2];
+		{
+			use crate::BERead;
+			let mut reader = &data[..];
+			val = reader.read().unwrap();
+			assert_eq!(val, [0xbaad, 0xf00d]);
+		}
+		{
+			use crate::LERead;
+			let mut reader = &data[..];
+			val = reader.read().unwrap();
+			assert_eq!(val, [0xadba, 0x0df0]);
+		}
+	}
+
+	#[test]
+	fn read_array_u8() {
+		let data = b"\xba\xad\xf0\x0d";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let val: [u8; 4] = reader.read().unwrap();
+		assert_eq!(&val, data);
+		assert!(reader.read::<[u8; 1]>().is_err());
+	}
+
+	#[test]
+	fn read_array_float() {
+		let data = b"\x3f\x80\x00\x00\xc0\x00\x00\x00";
+		use crate::BERead;
+		let mut reader = &data[..];
+		let val: [f32; 2] = reader.read().unwrap();
+		assert_eq!(val, [1.0, -2.0]);
+	}
+
+	#[test]
+	fn read_array_nested() {
+		let data = b"\x01\x02\x03\x04\x05\x06";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let val: [[i8; 2]; 3] = reader.read().unwrap();
+		assert_eq!(val, [[1, 2], [3, 4], [5, 6]]);
+	}
+
+	#[test]
+	fn read_array_partial_drop() {
+		use std::sync::atomic::{AtomicUsize, Ordering};
+
+		static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+		struct Test;
+		{
+			use crate::{Deserialize, Endianness, ERead};
+
+			impl<E: Endianness, R: ERead<E>> Deserialize<E, R> for Test where u8: Deserialize<E, R> {
+				fn deserialize(reader: &mut R) -> Res<Self> {
+					let _: u8 = reader.read()?;
+					Ok(Test)
+				}
+			}
+		}
+
+		impl Drop for Test {
+			fn drop(&mut self) {
+				DROPPED.fetch_add(1, Ordering::SeqCst);
+			}
+		}
+
+		let data = b"\x00\x00\x00";
+		use crate::LERead;
+		let mut reader = &data[..];
+		assert!(reader.read::<[Test; 5]>().is_err());
+		assert_eq!(DROPPED.load(Ordering::SeqCst), 3);
+	}
+
+	#[test]
+	fn read_tuple() {
+		let data = b"\xba\xad\x01\xba\xad\xf0\x0d";
+		let mut val: (u16, bool, u32);
+		{
+			use crate::BERead;
+			let mut reader = &data[..];
+			val = reader.read().unwrap();
+			assert_eq!(val, (0xbaad, true, 0xbaadf00d));
+		}
+		{
+			use crate::LERead;
+			let mut reader = &data[..];
+			val = reader.read().unwrap();
+			assert_eq!(val, (0xadba, true, 0x0df0adba));
+		}
+	}
+
+	#[test]
+	fn read_tuple_max_arity() {
+		let data = b"\x00\x01\x02\x03\x04\x05\x06\x07\x08\x09\x0a\x0b";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let val: (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8) = reader.read().unwrap();
+		assert_eq!(val, (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11));
+	}
+
 	#[test]
 	fn read_option_none() {
 		let data = b"\x00";
@@ -582,6 +1221,106 @@ mod tests {
 		assert_eq!(val, Some(0x002a));
 	}
 
+	#[test]
+	fn read_result_ok() {
+		let data = b"\x01\x2a\x00";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let val: Result<u16, u8> = reader.read().unwrap();
+		assert_eq!(val, Ok(0x002a));
+	}
+
+	#[test]
+	fn read_result_err() {
+		let data = b"\x00\x2a";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let val: Result<u16, u8> = reader.read().unwrap();
+		assert_eq!(val, Err(0x2a));
+	}
+
+	#[test]
+	fn read_unit() {
+		use std::marker::PhantomData;
+
+		let data = b"\x2a";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let _: () = reader.read().unwrap();
+		let _: PhantomData<u32> = reader.read().unwrap();
+		assert_eq!(reader, data);
+	}
+
+	#[test]
+	fn read_wrapper() {
+		use std::cmp::Reverse;
+		use std::num::Wrapping;
+		use std::rc::Rc;
+		use std::sync::Arc;
+
+		let data = b"\xba\xad\xf0\x0d\xba\xad\xf0\x0d\xba\xad";
+		use crate::BERead;
+		let mut reader = &data[..];
+		let val: Box<u16> = reader.read().unwrap();
+		assert_eq!(*val, 0xbaad);
+		let val: Rc<u16> = reader.read().unwrap();
+		assert_eq!(*val, 0xf00d);
+		let val: Arc<u16> = reader.read().unwrap();
+		assert_eq!(*val, 0xbaad);
+		let val: Wrapping<u16> = reader.read().unwrap();
+		assert_eq!(val, Wrapping(0xf00d));
+		let val: Reverse<u16> = reader.read().unwrap();
+		assert_eq!(val, Reverse(0xbaad));
+	}
+
+	#[test]
+	fn read_cow() {
+		use std::borrow::Cow;
+		use crate::NulString;
+
+		let data = b"hi\x00";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let val: Cow<NulString> = reader.read().unwrap();
+		assert!(matches!(val, Cow::Owned(x) if *x == "hi"));
+	}
+
+	#[test]
+	fn read_range() {
+		let data = b"\x01\x00\x02\x00";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let val: std::ops::Range<u16> = reader.read().unwrap();
+		assert_eq!(val, 1..2);
+		let mut reader = &data[..];
+		let val: std::ops::RangeInclusive<u16> = reader.read().unwrap();
+		assert_eq!(val, 1..=2);
+	}
+
+	#[test]
+	fn read_borrowed_array() {
+		let data = b"\xba\xad\xf0\x0d";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let val: &[u8; 3] = reader.read().unwrap();
+		assert_eq!(val, b"\xba\xad\xf0");
+		assert!(std::ptr::eq(val.as_ptr(), data.as_ptr()));
+		assert_eq!(reader, b"\x0d");
+		let err = reader.read::<&[u8; 2]>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+	}
+
+	#[test]
+	fn read_duration() {
+		let data = b"\x00\x00\x00\x00\x00\x00\x00\x02\x00\x00\x00\x03\x00\x00\x00\x00\x00\x00\x00\x00\x3b\x9a\xca\x00";
+		use crate::BERead;
+		let mut reader = &data[..];
+		let val: std::time::Duration = reader.read().unwrap();
+		assert_eq!(val, std::time::Duration::new(2, 3));
+		let err = reader.read::<std::time::Duration>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+	}
+
 	#[test]
 	fn read_struct_forced() {
 		struct Test {
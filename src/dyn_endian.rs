@@ -0,0 +1,260 @@
+use alloc::vec;
+
+use crate::io;
+use crate::io::Result as Res;
+use crate::{BigEndian, ByteRead, Deserialize, LittleEndian, Serialize};
+
+/**
+	An endianness chosen at runtime.
+
+	Usually the endianness is part of the type of a reader or writer, through the `BERead`/`LERead` and `BEWrite`/`LEWrite` traits. Some formats, like TIFF, pcap, ELF or Mach-O, declare their byte order in a header field instead. In that case, use this type to decide the endianness at runtime, either directly with its `read` and `write` methods, or through the [`DynEndian`] adapter.
+
+	## Examples
+
+	```
+	use endio::Endian;
+
+	let data = b"\xd4\xc3\xb2\xa1\x02\x00";
+	let endian = Endian::from_magic(data, &[0xa1, 0xb2, 0xc3, 0xd4]).unwrap();
+	assert_eq!(endian, Endian::Little);
+
+	let mut reader = &data[4..];
+	let major: u16 = endian.read(&mut reader).unwrap();
+	assert_eq!(major, 2);
+	```
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Endian {
+	Big,
+	Little,
+}
+
+impl Endian {
+	/// The endianness of the target platform.
+	#[cfg(target_endian="big")]
+	pub const NATIVE: Self = Self::Big;
+	/// The endianness of the target platform.
+	#[cfg(target_endian="little")]
+	pub const NATIVE: Self = Self::Little;
+	/// Network byte order, which is big endian.
+	pub const NETWORK: Self = Self::Big;
+
+	/**
+		Detects the endianness from a magic number at the start of `bytes`.
+
+		`magic` is the magic number's bytes in big endian order. Returns `Big` if `bytes` starts with `magic`, `Little` if it starts with `magic` reversed, and `None` otherwise.
+	*/
+	pub fn from_magic(bytes: &[u8], magic: &[u8]) -> Option<Self> {
+		if bytes.len() < magic.len() {
+			return None;
+		}
+		let bytes = &bytes[..magic.len()];
+		if bytes == magic {
+			Some(Self::Big)
+		} else if bytes.iter().eq(magic.iter().rev()) {
+			Some(Self::Little)
+		} else {
+			None
+		}
+	}
+
+	/**
+		Detects the endianness from a marker at the start of `bytes`, for formats where the markers aren't simply reversed, like TIFF's `MM` and `II`.
+
+		Returns `Big` if `bytes` starts with `big`, `Little` if it starts with `little`, and `None` otherwise.
+	*/
+	pub fn from_marker(bytes: &[u8], big: &[u8], little: &[u8]) -> Option<Self> {
+		if bytes.starts_with(big) {
+			Some(Self::Big)
+		} else if bytes.starts_with(little) {
+			Some(Self::Little)
+		} else {
+			None
+		}
+	}
+
+	/// Reads a `D` from the reader in this endianness.
+	pub fn read<D, R>(self, reader: &mut R) -> Res<D> where D: Deserialize<BigEndian, R> + Deserialize<LittleEndian, R> {
+		match self {
+			Self::Big => <D as Deserialize<BigEndian, R>>::deserialize(reader),
+			Self::Little => <D as Deserialize<LittleEndian, R>>::deserialize(reader),
+		}
+	}
+
+	/// Writes an `S` to the writer in this endianness.
+	pub fn write<S, W>(self, writer: &mut W, ser: S) -> Res<()> where S: Serialize<BigEndian, W> + Serialize<LittleEndian, W> {
+		match self {
+			Self::Big => <S as Serialize<BigEndian, W>>::serialize(ser, writer),
+			Self::Little => <S as Serialize<LittleEndian, W>>::serialize(ser, writer),
+		}
+	}
+}
+
+/**
+	A reader or writer with an endianness chosen at runtime.
+
+	This works like a reader or writer with the `ERead`/`EWrite` traits, except that the endianness is an [`Endian`] value instead of a type parameter. Any type that can be (de-)serialized in both big and little endian can be used with it. The `_be`-/`_le`-suffixed methods still force the endianness.
+
+	## Examples
+
+	```
+	use endio::{DynEndian, Endian};
+
+	let mut reader = DynEndian::from_marker(&b"MM\x00\x2a\x00\x00\x00\x08"[..], b"MM", b"II").unwrap();
+	assert_eq!(reader.endian(), Endian::Big);
+	let version: u16 = reader.read().unwrap();
+	let offset: u32 = reader.read().unwrap();
+	assert_eq!((version, offset), (42, 8));
+
+	let mut writer = DynEndian::new(vec![], Endian::Little);
+	writer.write(42u16).unwrap();
+	assert_eq!(writer.into_inner(), b"\x2a\x00");
+	```
+*/
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DynEndian<T> {
+	inner: T,
+	endian: Endian,
+}
+
+impl<T> DynEndian<T> {
+	/// Wraps the reader or writer, using `endian` for (de-)serialization.
+	pub fn new(inner: T, endian: Endian) -> Self {
+		Self { inner, endian }
+	}
+
+	/// Unwraps the reader or writer.
+	pub fn into_inner(self) -> T {
+		self.inner
+	}
+
+	/// Returns a reference to the underlying reader or writer.
+	pub fn get_ref(&self) -> &T {
+		&self.inner
+	}
+
+	/// Returns a mutable reference to the underlying reader or writer.
+	pub fn get_mut(&mut self) -> &mut T {
+		&mut self.inner
+	}
+
+	/// Returns the endianness used for (de-)serialization.
+	pub fn endian(&self) -> Endian {
+		self.endian
+	}
+
+	/// Changes the endianness used for (de-)serialization.
+	pub fn set_endian(&mut self, endian: Endian) {
+		self.endian = endian;
+	}
+
+	/// Reads a `Deserialize` from the reader, in the reader's endianness.
+	pub fn read<D>(&mut self) -> Res<D> where D: Deserialize<BigEndian, T> + Deserialize<LittleEndian, T> {
+		self.endian.read(&mut self.inner)
+	}
+
+	/// Reads in forced big endian.
+	pub fn read_be<D: Deserialize<BigEndian, T>>(&mut self) -> Res<D> {
+		D::deserialize(&mut self.inner)
+	}
+
+	/// Reads in forced little endian.
+	pub fn read_le<D: Deserialize<LittleEndian, T>>(&mut self) -> Res<D> {
+		D::deserialize(&mut self.inner)
+	}
+
+	/// Writes a `Serialize` to the writer, in the writer's endianness.
+	pub fn write<S>(&mut self, ser: S) -> Res<()> where S: Serialize<BigEndian, T> + Serialize<LittleEndian, T> {
+		self.endian.write(&mut self.inner, ser)
+	}
+
+	/// Writes in forced big endian.
+	pub fn write_be<S: Serialize<BigEndian, T>>(&mut self, ser: S) -> Res<()> {
+		ser.serialize(&mut self.inner)
+	}
+
+	/// Writes in forced little endian.
+	pub fn write_le<S: Serialize<LittleEndian, T>>(&mut self, ser: S) -> Res<()> {
+		ser.serialize(&mut self.inner)
+	}
+}
+
+impl<T: ByteRead> DynEndian<T> {
+	/**
+		Reads a magic number given in big endian order, and uses the endianness it was stored in.
+
+		Returns an `InvalidData` error if the bytes read are neither the magic number nor the reversed magic number. See [`Endian::from_magic`].
+	*/
+	pub fn from_magic(inner: T, magic: &[u8]) -> Res<Self> {
+		Self::detect(inner, magic.len(), |bytes| Endian::from_magic(bytes, magic))
+	}
+
+	/**
+		Reads a byte order marker, and uses the endianness it indicates.
+
+		`big` and `little` need to have the same length. Returns an `InvalidData` error if the bytes read are neither of the two markers. See [`Endian::from_marker`].
+	*/
+	pub fn from_marker(inner: T, big: &[u8], little: &[u8]) -> Res<Self> {
+		assert_eq!(big.len(), little.len(), "byte order markers need to have the same length");
+		Self::detect(inner, big.len(), |bytes| Endian::from_marker(bytes, big, little))
+	}
+
+	fn detect(mut inner: T, len: usize, detect: impl FnOnce(&[u8]) -> Option<Endian>) -> Res<Self> {
+		let mut buf = vec![0; len];
+		inner.read_exact(&mut buf)?;
+		let endian = detect(&buf).ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "magic number did not match in either endianness"))?;
+		Ok(Self::new(inner, endian))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::io;
+	use crate::{DynEndian, Endian};
+
+	#[test]
+	fn from_magic() {
+		let magic = [0xfe, 0xed, 0xfa, 0xce];
+		assert_eq!(Endian::from_magic(b"\xfe\xed\xfa\xce\x00", &magic), Some(Endian::Big));
+		assert_eq!(Endian::from_magic(b"\xce\xfa\xed\xfe", &magic), Some(Endian::Little));
+		assert_eq!(Endian::from_magic(b"\xce\xfa\xed\xff", &magic), None);
+		assert_eq!(Endian::from_magic(b"\xce\xfa\xed", &magic), None);
+	}
+
+	#[test]
+	fn from_marker() {
+		assert_eq!(Endian::from_marker(b"II*\x00", b"MM", b"II"), Some(Endian::Little));
+		assert_eq!(Endian::from_marker(b"MM\x00*", b"MM", b"II"), Some(Endian::Big));
+		assert_eq!(Endian::from_marker(b"IM", b"MM", b"II"), None);
+	}
+
+	#[test]
+	fn read_dyn() {
+		let data = b"\xce\xfa\xed\xfe\xba\xad\xba\xad";
+		let mut reader = DynEndian::from_magic(&data[..], &[0xfe, 0xed, 0xfa, 0xce]).unwrap();
+		assert_eq!(reader.endian(), Endian::Little);
+		let val: u16 = reader.read().unwrap();
+		assert_eq!(val, 0xadba);
+		let val: u16 = reader.read_be().unwrap();
+		assert_eq!(val, 0xbaad);
+		assert!(reader.get_ref().is_empty());
+	}
+
+	#[test]
+	fn read_dyn_no_match() {
+		let err = DynEndian::from_marker(&b"XX"[..], b"MM", b"II").unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+		let err = DynEndian::from_magic(&b"\xfe"[..], &[0xfe, 0xed]).unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+	}
+
+	#[test]
+	fn write_dyn() {
+		let mut writer = DynEndian::new(vec![], Endian::Big);
+		writer.write(0xbaadu16).unwrap();
+		writer.set_endian(Endian::Little);
+		writer.write(&[0xbaadu16][..]).unwrap();
+		writer.write_be(0xf00du16).unwrap();
+		assert_eq!(writer.into_inner(), b"\xba\xad\xad\xba\xf0\x0d");
+	}
+}
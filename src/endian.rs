@@ -28,6 +28,25 @@ pub type BE = BigEndian;
 /// Shorthand for `LittleEndian`.
 pub type LE = LittleEndian;
 
+/**
+	The endianness of the target platform.
+
+	This is an alias of `BigEndian` or `LittleEndian`, decided at compile time, so using it has no runtime cost.
+*/
+#[cfg(target_endian="big")]
+pub type NativeEndian = BigEndian;
+/**
+	The endianness of the target platform.
+
+	This is an alias of `BigEndian` or `LittleEndian`, decided at compile time, so using it has no runtime cost.
+*/
+#[cfg(target_endian="little")]
+pub type NativeEndian = LittleEndian;
+/// Network byte order, which is big endian.
+pub type NetworkEndian = BigEndian;
+/// Shorthand for `NativeEndian`.
+pub type NE = NativeEndian;
+
 // ensures no one else implements the trait
 mod private {
 	pub trait Sealed {}
@@ -0,0 +1,338 @@
+use core::convert::TryFrom;
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::io::Result as Res;
+use crate::{Deserialize, Endianness, ERead, EWrite, Serialize};
+
+/**
+	A binary fixed-point number with `FRAC` fractional bits, stored as the integer `I`.
+
+	The represented value is `I / 2^FRAC`, so for example 16.16 fixed point is `Fixed<i32, 16>` and 8.8 fixed point is `Fixed<u16, 8>`. (De-)serialized as the underlying integer, in the stream's endianness.
+
+	Conversion from `f32` and `f64` rounds to the nearest representable value, with ties rounding away from zero. Values outside of the representable range saturate to the minimum or maximum, and NaN becomes zero. Conversion to `f32` and `f64` rounds to the nearest float if the integer has more bits than the float's mantissa.
+
+	Addition, subtraction and negation behave like they do for the underlying integer. Multiplication rounds toward negative infinity and division rounds toward zero, both panic if the result does not fit in `I`. Multiplication and division are only available for integers up to 64 bits, and only if `FRAC` is at most the number of bits of `I`. This is checked at compile time:
+
+	```compile_fail
+	use endio::Fixed;
+
+	let _ = Fixed::<i32, 40>(1) * Fixed::<i32, 40>(1);
+	```
+
+	## Examples
+
+	```
+	use endio::{BERead, BEWrite, Fixed};
+
+	let mut reader = &b"\x00\x01\x80\x00"[..];
+	let val: Fixed<i32, 16> = reader.read().unwrap();
+	assert_eq!(val.to_f64(), 1.5);
+
+	let mut writer = vec![];
+	writer.write(val * Fixed::<i32, 16>::from_f64(-2.0)).unwrap();
+	assert_eq!(writer, b"\xff\xfd\x00\x00");
+	```
+*/
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed<I, const FRAC: u32>(pub I);
+
+impl<I, const FRAC: u32> Fixed<I, FRAC> {
+	/// Creates a value from the underlying integer.
+	pub const fn from_bits(bits: I) -> Self {
+		Self(bits)
+	}
+
+	/// Returns the underlying integer.
+	pub fn to_bits(self) -> I {
+		self.0
+	}
+}
+
+/// Computes `2^exp` exactly, or saturates to zero or infinity. Like `f64::powi`, which isn't available without `std`.
+fn exp2(exp: i32) -> f64 {
+	match exp {
+		..=-1075 => 0.0,
+		-1074..=-1023 => f64::from_bits(1 << (exp + 1074)),
+		-1022..=1023 => f64::from_bits(((exp + 1023) as u64) << 52),
+		_ => f64::INFINITY,
+	}
+}
+
+/// Rounds to the nearest integer, with ties rounding away from zero. Like `f64::round`, which isn't available without `std`.
+fn round(value: f64) -> f64 {
+	let bits = value.to_bits();
+	let exp = ((bits >> 52) & 0x7ff) as i32 - 1023;
+	let trunc = match exp {
+		..=-1 => f64::from_bits(bits & (1 << 63)),
+		0..=51 => f64::from_bits(bits & !((1 << (52 - exp)) - 1)),
+		// already an integer, infinity or NaN
+		_ => return value,
+	};
+	let frac = value - trunc;
+	if frac >= 0.5 {
+		trunc + 1.0
+	} else if frac <= -0.5 {
+		trunc - 1.0
+	} else {
+		trunc
+	}
+}
+
+macro_rules! impl_fixed {
+	($int:ident) => {
+		impl<const FRAC: u32> Fixed<$int, FRAC> {
+			/// Converts an `f64`, rounding to the nearest representable value.
+			pub fn from_f64(value: f64) -> Self {
+				Self(round(value * exp2(FRAC as i32)) as $int)
+			}
+
+			/// Converts an `f32`, rounding to the nearest representable value.
+			pub fn from_f32(value: f32) -> Self {
+				Self::from_f64(value.into())
+			}
+
+			/// Converts to an `f64`.
+			pub fn to_f64(self) -> f64 {
+				self.0 as f64 * exp2(-(FRAC as i32))
+			}
+
+			/// Converts to an `f32`.
+			pub fn to_f32(self) -> f32 {
+				self.0 as f32 * exp2(-(FRAC as i32)) as f32
+			}
+		}
+
+		impl<const FRAC: u32> fmt::Display for Fixed<$int, FRAC> {
+			fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				fmt::Display::fmt(&self.to_f64(), f)
+			}
+		}
+
+		impl<const FRAC: u32> Add for Fixed<$int, FRAC> {
+			type Output = Self;
+
+			fn add(self, rhs: Self) -> Self {
+				Self(self.0 + rhs.0)
+			}
+		}
+
+		impl<const FRAC: u32> Sub for Fixed<$int, FRAC> {
+			type Output = Self;
+
+			fn sub(self, rhs: Self) -> Self {
+				Self(self.0 - rhs.0)
+			}
+		}
+
+		impl<const FRAC: u32> AddAssign for Fixed<$int, FRAC> {
+			fn add_assign(&mut self, rhs: Self) {
+				*self = *self + rhs;
+			}
+		}
+
+		impl<const FRAC: u32> SubAssign for Fixed<$int, FRAC> {
+			fn sub_assign(&mut self, rhs: Self) {
+				*self = *self - rhs;
+			}
+		}
+	}
+}
+
+macro_rules! impl_fixed_mul {
+	($int:ident, $wide:ident) => {
+		impl<const FRAC: u32> Fixed<$int, FRAC> {
+			/// Fails to compile if shifting by `FRAC` could overflow the wider integer used for multiplication and division.
+			const FRAC_FITS: () = assert!(FRAC <= $int::BITS, "FRAC must not exceed the number of bits of the integer");
+		}
+
+		impl<const FRAC: u32> Mul for Fixed<$int, FRAC> {
+			type Output = Self;
+
+			fn mul(self, rhs: Self) -> Self {
+				let () = Self::FRAC_FITS;
+				let res = (self.0 as $wide * rhs.0 as $wide) >> FRAC;
+				Self($int::try_from(res).expect("attempt to multiply with overflow"))
+			}
+		}
+
+		impl<const FRAC: u32> Div for Fixed<$int, FRAC> {
+			type Output = Self;
+
+			fn div(self, rhs: Self) -> Self {
+				let () = Self::FRAC_FITS;
+				let res = ((self.0 as $wide) << FRAC) / rhs.0 as $wide;
+				Self($int::try_from(res).expect("attempt to divide with overflow"))
+			}
+		}
+
+		impl<const FRAC: u32> MulAssign for Fixed<$int, FRAC> {
+			fn mul_assign(&mut self, rhs: Self) {
+				*self = *self * rhs;
+			}
+		}
+
+		impl<const FRAC: u32> DivAssign for Fixed<$int, FRAC> {
+			fn div_assign(&mut self, rhs: Self) {
+				*self = *self / rhs;
+			}
+		}
+	}
+}
+
+macro_rules! impl_fixed_neg {
+	($int:ident) => {
+		impl<const FRAC: u32> Neg for Fixed<$int, FRAC> {
+			type Output = Self;
+
+			fn neg(self) -> Self {
+				Self(-self.0)
+			}
+		}
+	}
+}
+
+impl_fixed!(u8);
+impl_fixed!(u16);
+impl_fixed!(u32);
+impl_fixed!(u64);
+impl_fixed!(u128);
+impl_fixed!(i8);
+impl_fixed!(i16);
+impl_fixed!(i32);
+impl_fixed!(i64);
+impl_fixed!(i128);
+
+impl_fixed_mul!(u8, u128);
+impl_fixed_mul!(u16, u128);
+impl_fixed_mul!(u32, u128);
+impl_fixed_mul!(u64, u128);
+impl_fixed_mul!(i8, i128);
+impl_fixed_mul!(i16, i128);
+impl_fixed_mul!(i32, i128);
+impl_fixed_mul!(i64, i128);
+
+impl_fixed_neg!(i8);
+impl_fixed_neg!(i16);
+impl_fixed_neg!(i32);
+impl_fixed_neg!(i64);
+impl_fixed_neg!(i128);
+
+impl<E: Endianness, R: ERead<E>, I, const FRAC: u32> Deserialize<E, R> for Fixed<I, FRAC> where I: Deserialize<E, R> {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		Ok(Self(reader.read()?))
+	}
+}
+
+impl<E: Endianness, W: EWrite<E>, I, const FRAC: u32> Serialize<E, W> for Fixed<I, FRAC> where I: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		writer.write(self.0)
+	}
+}
+
+impl<E: Endianness, W: EWrite<E>, I: Copy, const FRAC: u32> Serialize<E, W> for &Fixed<I, FRAC> where I: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		writer.write(self.0)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::Fixed;
+
+	#[test]
+	fn from_float() {
+		assert_eq!(Fixed::<i32, 16>::from_f64(1.5).0, 0x1_8000);
+		assert_eq!(Fixed::<i32, 16>::from_f32(-1.5).0, -0x1_8000);
+		assert_eq!(Fixed::<u16, 8>::from_f64(0.5 / 256.0).0, 1);
+		assert_eq!(Fixed::<u16, 8>::from_f64(0.49 / 256.0).0, 0);
+		assert_eq!(Fixed::<i16, 8>::from_f64(-0.5 / 256.0).0, -1);
+		assert_eq!(Fixed::<u16, 8>::from_f64(1000.0).0, u16::MAX);
+		assert_eq!(Fixed::<u16, 8>::from_f64(-1.0).0, 0);
+		assert_eq!(Fixed::<i16, 8>::from_f64(f64::NAN).0, 0);
+	}
+
+	#[test]
+	fn round_matches_std() {
+		let values = [0.0, -0.0, 0.3, 0.5, -0.5, 0.49999999999999994, 1.5, -2.5, 2.5000001, 4503599627370495.5, 1e300, -1e-300, f64::INFINITY, f64::NEG_INFINITY, f64::MAX];
+		for &value in &values {
+			assert_eq!(super::round(value).to_bits(), value.round().to_bits(), "{}", value);
+		}
+		assert!(super::round(f64::NAN).is_nan());
+		for exp in -1022..=1023 {
+			assert_eq!(super::exp2(exp), 2f64.powi(exp), "{}", exp);
+		}
+		assert_eq!(super::exp2(-1074), f64::from_bits(1));
+		assert_eq!(super::exp2(-1075), 0.0);
+		assert_eq!(super::exp2(1024), f64::INFINITY);
+	}
+
+	#[test]
+	fn to_float() {
+		assert_eq!(Fixed::<i32, 16>(-0x1_8000).to_f64(), -1.5);
+		assert_eq!(Fixed::<u16, 8>(0x0180).to_f32(), 1.5);
+		assert_eq!(Fixed::<u8, 0>(200).to_f32(), 200.0);
+		assert_eq!(Fixed::<i128, 64>(1 << 63).to_f64(), 0.5);
+	}
+
+	#[test]
+	fn arithmetic() {
+		let a = Fixed::<i32, 16>::from_f64(2.5);
+		let b = Fixed::<i32, 16>::from_f64(-0.5);
+		assert_eq!((a + b).to_f64(), 2.0);
+		assert_eq!((a - b).to_f64(), 3.0);
+		assert_eq!((a * b).to_f64(), -1.25);
+		assert_eq!((a / b).to_f64(), -5.0);
+		assert_eq!((-a).to_f64(), -2.5);
+		let mut c = a;
+		c *= a;
+		c -= b;
+		assert_eq!(c.to_f64(), 6.75);
+		assert_eq!((Fixed::<u8, 4>(1) * Fixed::<u8, 4>(1)).0, 0);
+	}
+
+	#[test]
+	fn arithmetic_all_frac_bits() {
+		let a = Fixed::<i64, 64>::from_f64(-0.125);
+		let b = Fixed::<i64, 64>::from_f64(0.25);
+		assert_eq!((a * b).to_f64(), -0.03125);
+		assert_eq!((a / b).0, i64::MIN);
+		let a = Fixed::<u64, 64>::from_f64(0.75);
+		let b = Fixed::<u64, 64>::from_f64(0.5);
+		assert_eq!((a * b).to_f64(), 0.375);
+		assert_eq!((b / a).0, 0xaaaa_aaaa_aaaa_aaaa);
+	}
+
+	#[test]
+	#[should_panic]
+	fn mul_overflow() {
+		let _ = Fixed::<u16, 8>::from_f64(200.0) * Fixed::<u16, 8>::from_f64(2.0);
+	}
+
+	#[test]
+	fn read_fixed() {
+		let data = b"\x80\x01";
+		let mut val: Fixed<u16, 8>;
+		{
+			use crate::BERead;
+			let mut reader = &data[..];
+			val = reader.read().unwrap();
+			assert_eq!(val.to_f32(), 128.0 + 1.0 / 256.0);
+		}
+		{
+			use crate::LERead;
+			let mut reader = &data[..];
+			val = reader.read().unwrap();
+			assert_eq!(val.to_f32(), 1.5);
+		}
+	}
+
+	#[test]
+	fn write_fixed() {
+		let data = b"\x00\x80\xff\xff";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(Fixed::<i32, 16>::from_f64(-0.5)).unwrap();
+		assert_eq!(writer, data);
+	}
+}
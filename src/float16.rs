@@ -0,0 +1,346 @@
+use core::cmp::Ordering;
+use core::fmt;
+
+use crate::io::Result as Res;
+use crate::{Deserialize, Endianness, ERead, EWrite, Serialize};
+
+/**
+	An IEEE 754 half-precision (binary16) floating point number.
+
+	(De-)serialized as its bits, as a `u16` in the stream's endianness. Conversion from `f32` rounds to the nearest representable value, with ties to even. Values too large for half precision become infinity, and values too small become (signed) zero or subnormals. Conversion to `f32` and `f64` is exact. NaN payloads are preserved as far as they fit, and NaNs stay NaNs.
+
+	Comparison follows float semantics, so NaN is not equal to itself and `-0.0` equals `0.0`.
+
+	## Examples
+
+	```
+	use endio::{F16, LERead, LEWrite};
+
+	let mut reader = &b"\x00\x3c"[..];
+	let val: F16 = reader.read().unwrap();
+	assert_eq!(val.to_f32(), 1.0);
+
+	let mut writer = vec![];
+	writer.write(F16::from_f32(-2.5)).unwrap();
+	assert_eq!(writer, b"\x00\xc1");
+	```
+*/
+#[derive(Clone, Copy, Default)]
+pub struct F16(u16);
+
+/**
+	A bfloat16 ("brain floating point") number, which has the same exponent range as `f32` but only 8 bits of precision.
+
+	(De-)serialized as its bits, as a `u16` in the stream's endianness. Conversion from `f32` rounds to the nearest representable value, with ties to even, and conversion to `f32` and `f64` is exact. NaNs stay NaNs.
+
+	Comparison follows float semantics, so NaN is not equal to itself and `-0.0` equals `0.0`.
+
+	## Examples
+
+	```
+	use endio::{BEWrite, BF16};
+
+	let mut writer = vec![];
+	writer.write(BF16::from_f32(1.0)).unwrap();
+	assert_eq!(writer, b"\x3f\x80");
+	```
+*/
+#[derive(Clone, Copy, Default)]
+pub struct BF16(u16);
+
+/// Shifts `value` right by `shift` bits, rounding to nearest with ties to even.
+fn round_shift(value: u32, shift: u32) -> u32 {
+	let half = 1 << (shift - 1);
+	let rem = value & ((1 << shift) - 1);
+	let res = value >> shift;
+	if rem > half || (rem == half && res & 1 == 1) {
+		res + 1
+	} else {
+		res
+	}
+}
+
+impl F16 {
+	/// Creates a value from its raw bits.
+	pub const fn from_bits(bits: u16) -> Self {
+		Self(bits)
+	}
+
+	/// Returns the raw bits of the value.
+	pub const fn to_bits(self) -> u16 {
+		self.0
+	}
+
+	/// Converts an `f32`, rounding to the nearest representable value.
+	pub fn from_f32(value: f32) -> Self {
+		let bits = value.to_bits();
+		let sign = ((bits >> 16) & 0x8000) as u16;
+		let exp = ((bits >> 23) & 0xff) as i32;
+		let man = bits & 0x7f_ffff;
+		if exp == 0xff {
+			if man == 0 {
+				return Self(sign | 0x7c00);
+			}
+			// keep the top of the payload, and make sure the NaN stays quiet and doesn't turn into infinity
+			return Self(sign | 0x7e00 | (man >> 13) as u16);
+		}
+		let half_exp = exp - 127 + 15;
+		if half_exp >= 0x1f {
+			return Self(sign | 0x7c00);
+		}
+		if half_exp <= 0 {
+			// subnormal or zero, f32 subnormals are far too small to matter
+			let shift = (14 - half_exp) as u32;
+			if shift > 24 {
+				return Self(sign);
+			}
+			// rounding up may carry into the smallest normal exponent, which gives the right bits
+			return Self(sign | round_shift(man | 0x80_0000, shift) as u16);
+		}
+		// rounding up may carry into the exponent, even up to infinity, which gives the right bits
+		Self(sign | (((half_exp as u32) << 10) + round_shift(man, 13)) as u16)
+	}
+
+	/// Converts to an `f32`. This is exact.
+	pub fn to_f32(self) -> f32 {
+		let sign = ((self.0 & 0x8000) as u32) << 16;
+		let exp = ((self.0 >> 10) & 0x1f) as u32;
+		let man = (self.0 & 0x3ff) as u32;
+		let bits = match exp {
+			0x1f => sign | 0x7f80_0000 | (man << 13),
+			0 if man == 0 => sign,
+			0 => {
+				// subnormal, normalize so that the implicit bit is at bit 10
+				let shift = man.leading_zeros() - 21;
+				sign | ((113 - shift) << 23) | (((man << shift) & 0x3ff) << 13)
+			}
+			_ => sign | ((exp + 112) << 23) | (man << 13),
+		};
+		f32::from_bits(bits)
+	}
+
+	/// Returns whether the value is NaN.
+	pub fn is_nan(self) -> bool {
+		self.0 & 0x7fff > 0x7c00
+	}
+}
+
+impl BF16 {
+	/// Creates a value from its raw bits.
+	pub const fn from_bits(bits: u16) -> Self {
+		Self(bits)
+	}
+
+	/// Returns the raw bits of the value.
+	pub const fn to_bits(self) -> u16 {
+		self.0
+	}
+
+	/// Converts an `f32`, rounding to the nearest representable value.
+	pub fn from_f32(value: f32) -> Self {
+		let bits = value.to_bits();
+		if value.is_nan() {
+			// make sure the NaN stays quiet and doesn't turn into infinity
+			return Self((bits >> 16) as u16 | 0x40);
+		}
+		// rounding up may carry into the exponent, even up to infinity, which gives the right bits
+		Self(round_shift(bits, 16) as u16)
+	}
+
+	/// Converts to an `f32`. This is exact.
+	pub fn to_f32(self) -> f32 {
+		f32::from_bits((self.0 as u32) << 16)
+	}
+
+	/// Returns whether the value is NaN.
+	pub fn is_nan(self) -> bool {
+		self.0 & 0x7fff > 0x7f80
+	}
+}
+
+macro_rules! impl_float16 {
+	($t:ident) => {
+		impl From<$t> for f32 {
+			fn from(value: $t) -> Self {
+				value.to_f32()
+			}
+		}
+
+		impl From<$t> for f64 {
+			fn from(value: $t) -> Self {
+				value.to_f32().into()
+			}
+		}
+
+		impl PartialEq for $t {
+			fn eq(&self, other: &Self) -> bool {
+				self.to_f32() == other.to_f32()
+			}
+		}
+
+		impl PartialOrd for $t {
+			fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+				self.to_f32().partial_cmp(&other.to_f32())
+			}
+		}
+
+		impl fmt::Debug for $t {
+			fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				fmt::Debug::fmt(&self.to_f32(), f)
+			}
+		}
+
+		impl fmt::Display for $t {
+			fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				fmt::Display::fmt(&self.to_f32(), f)
+			}
+		}
+
+		impl<E: Endianness, R: ERead<E>> Deserialize<E, R> for $t where u16: Deserialize<E, R> {
+			fn deserialize(reader: &mut R) -> Res<Self> {
+				Ok(Self(reader.read()?))
+			}
+		}
+
+		impl<E: Endianness, W: EWrite<E>> Serialize<E, W> for $t where u16: Serialize<E, W> {
+			fn serialize(self, writer: &mut W) -> Res<()> {
+				writer.write(self.0)
+			}
+		}
+
+		impl<E: Endianness, W: EWrite<E>> Serialize<E, W> for &$t where u16: Serialize<E, W> {
+			fn serialize(self, writer: &mut W) -> Res<()> {
+				writer.write(self.0)
+			}
+		}
+	}
+}
+
+impl_float16!(F16);
+impl_float16!(BF16);
+
+#[cfg(feature="half")]
+impl From<half::f16> for F16 {
+	fn from(value: half::f16) -> Self {
+		Self(value.to_bits())
+	}
+}
+
+#[cfg(feature="half")]
+impl From<F16> for half::f16 {
+	fn from(value: F16) -> Self {
+		Self::from_bits(value.0)
+	}
+}
+
+#[cfg(feature="half")]
+impl From<half::bf16> for BF16 {
+	fn from(value: half::bf16) -> Self {
+		Self(value.to_bits())
+	}
+}
+
+#[cfg(feature="half")]
+impl From<BF16> for half::bf16 {
+	fn from(value: BF16) -> Self {
+		Self::from_bits(value.0)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{BF16, F16};
+
+	#[test]
+	fn f16_to_f32() {
+		assert_eq!(F16::from_bits(0x3c00).to_f32(), 1.0);
+		assert_eq!(F16::from_bits(0xc000).to_f32(), -2.0);
+		assert_eq!(F16::from_bits(0x7bff).to_f32(), 65504.0);
+		assert_eq!(F16::from_bits(0x0400).to_f32(), 2f32.powi(-14));
+		assert_eq!(F16::from_bits(0x0001).to_f32(), 2f32.powi(-24));
+		assert_eq!(F16::from_bits(0x03ff).to_f32(), 1023.0 * 2f32.powi(-24));
+		assert_eq!(F16::from_bits(0x8000).to_f32().to_bits(), (-0f32).to_bits());
+		assert_eq!(F16::from_bits(0x7c00).to_f32(), f32::INFINITY);
+		assert_eq!(F16::from_bits(0xfc00).to_f32(), f32::NEG_INFINITY);
+		assert!(F16::from_bits(0x7c01).to_f32().is_nan());
+		assert!(F16::from_bits(0x7c01).is_nan());
+	}
+
+	#[test]
+	fn f16_from_f32() {
+		assert_eq!(F16::from_f32(1.0).to_bits(), 0x3c00);
+		assert_eq!(F16::from_f32(65504.0).to_bits(), 0x7bff);
+		assert_eq!(F16::from_f32(65520.0).to_bits(), 0x7c00);
+		assert_eq!(F16::from_f32(1e10).to_bits(), 0x7c00);
+		assert_eq!(F16::from_f32(f32::NEG_INFINITY).to_bits(), 0xfc00);
+		assert_eq!(F16::from_f32(2f32.powi(-14)).to_bits(), 0x0400);
+		assert_eq!(F16::from_f32(2f32.powi(-24)).to_bits(), 0x0001);
+		assert_eq!(F16::from_f32(2f32.powi(-25)).to_bits(), 0x0000);
+		assert_eq!(F16::from_f32(1.5 * 2f32.powi(-25)).to_bits(), 0x0001);
+		assert_eq!(F16::from_f32(-1e-10).to_bits(), 0x8000);
+		// 1 + 2^-11 is exactly between 1 and the next value, ties to even
+		assert_eq!(F16::from_f32(1.0 + 2f32.powi(-11)).to_bits(), 0x3c00);
+		assert_eq!(F16::from_f32(1.0 + 3.0 * 2f32.powi(-11)).to_bits(), 0x3c02);
+		assert!(F16::from_f32(f32::NAN).is_nan());
+		assert!(F16::from_f32(f32::from_bits(0x7f80_0001)).is_nan());
+	}
+
+	#[test]
+	fn f16_round_trip() {
+		for bits in 0..=u16::MAX {
+			let val = F16::from_bits(bits);
+			if !val.is_nan() {
+				assert_eq!(F16::from_f32(val.to_f32()).to_bits(), bits);
+			}
+		}
+	}
+
+	#[test]
+	fn bf16_conversion() {
+		assert_eq!(BF16::from_f32(1.0).to_bits(), 0x3f80);
+		assert_eq!(BF16::from_bits(0xc040).to_f32(), -3.0);
+		assert_eq!(BF16::from_f32(f32::MAX).to_bits(), 0x7f80);
+		assert_eq!(BF16::from_f32(f32::from_bits(0x3f80_8000)).to_bits(), 0x3f80);
+		assert_eq!(BF16::from_f32(f32::from_bits(0x3f81_8000)).to_bits(), 0x3f82);
+		assert!(BF16::from_f32(f32::from_bits(0x7f80_0001)).is_nan());
+		for bits in 0..=u16::MAX {
+			let val = BF16::from_bits(bits);
+			if !val.is_nan() {
+				assert_eq!(BF16::from_f32(val.to_f32()).to_bits(), bits);
+			}
+		}
+	}
+
+	#[test]
+	fn read_f16() {
+		let data = b"\x3c\x00";
+		let mut val: F16;
+		{
+			use crate::BERead;
+			let mut reader = &data[..];
+			val = reader.read().unwrap();
+			assert_eq!(val.to_f32(), 1.0);
+		}
+		{
+			use crate::LERead;
+			let mut reader = &data[..];
+			val = reader.read().unwrap();
+			assert_eq!(val.to_f32(), 2f32.powi(-24) * 60.0);
+		}
+	}
+
+	#[test]
+	fn write_bf16() {
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(BF16::from_f32(-3.0)).unwrap();
+		assert_eq!(writer, b"\x40\xc0");
+	}
+
+	#[cfg(feature="half")]
+	#[test]
+	fn half_interop() {
+		assert_eq!(half::f16::from(F16::from_f32(1.5)), half::f16::from_f32(1.5));
+		assert_eq!(BF16::from(half::bf16::from_f32(1.5)), BF16::from_f32(1.5));
+	}
+}
@@ -0,0 +1,201 @@
+use core::ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Deref, DerefMut, Div, DivAssign, Mul, MulAssign, Not, Rem, RemAssign, Sub, SubAssign};
+
+use crate::io::Result as Res;
+use crate::{BigEndian, Deserialize, Endianness, ERead, EWrite, LittleEndian, Serialize};
+
+/**
+	A value that is always (de-)serialized in **big** endian, regardless of the stream's endianness.
+
+	This is useful for fields that have a fixed byte order inside a structure of the other byte order, like sizes next to a FourCC or network ports. It's the field-level equivalent of the `read_be`/`write_be` methods, and can be used in derived structs like any other type.
+
+	## Examples
+
+	```
+	use endio::{Be, LERead, LEWrite};
+
+	let mut reader = &b"\xba\xad\xba\xad"[..];
+	let a: u16 = reader.read().unwrap();
+	let b: Be<u16> = reader.read().unwrap();
+	assert_eq!(a, 0xadba);
+	assert_eq!(b, Be(0xbaad));
+	assert_eq!(u16::from(b + Be(1)), 0xbaae);
+
+	let mut writer = vec![];
+	writer.write(Be(0xbaadu16)).unwrap();
+	assert_eq!(writer, b"\xba\xad");
+	```
+*/
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Be<T>(pub T);
+
+/**
+	A value that is always (de-)serialized in **little** endian, regardless of the stream's endianness.
+
+	See [`Be`] for details.
+
+	## Examples
+
+	```
+	use endio::{BERead, Le};
+
+	let mut reader = &b"\xba\xad"[..];
+	let val: Le<u16> = reader.read().unwrap();
+	assert_eq!(*val, 0xadba);
+	```
+*/
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Le<T>(pub T);
+
+macro_rules! impl_forced_op {
+	($t:ident, $op:ident, $op_fn:ident, $op_assign:ident, $op_assign_fn:ident) => {
+		impl<T: $op<Output=T>> $op for $t<T> {
+			type Output = Self;
+
+			fn $op_fn(self, rhs: Self) -> Self {
+				Self(self.0.$op_fn(rhs.0))
+			}
+		}
+
+		impl<T: $op_assign> $op_assign for $t<T> {
+			fn $op_assign_fn(&mut self, rhs: Self) {
+				self.0.$op_assign_fn(rhs.0);
+			}
+		}
+	}
+}
+
+macro_rules! impl_forced {
+	($t:ident, $e:ident, $read:ident, $write:ident) => {
+		impl<T> $t<T> {
+			/// Unwraps the value.
+			pub fn into_inner(self) -> T {
+				self.0
+			}
+		}
+
+		impl<T> From<T> for $t<T> {
+			fn from(value: T) -> Self {
+				Self(value)
+			}
+		}
+
+		impl<T> Deref for $t<T> {
+			type Target = T;
+
+			fn deref(&self) -> &T {
+				&self.0
+			}
+		}
+
+		impl<T> DerefMut for $t<T> {
+			fn deref_mut(&mut self) -> &mut T {
+				&mut self.0
+			}
+		}
+
+		impl_forced_op!($t, Add, add, AddAssign, add_assign);
+		impl_forced_op!($t, Sub, sub, SubAssign, sub_assign);
+		impl_forced_op!($t, Mul, mul, MulAssign, mul_assign);
+		impl_forced_op!($t, Div, div, DivAssign, div_assign);
+		impl_forced_op!($t, Rem, rem, RemAssign, rem_assign);
+		impl_forced_op!($t, BitAnd, bitand, BitAndAssign, bitand_assign);
+		impl_forced_op!($t, BitOr, bitor, BitOrAssign, bitor_assign);
+		impl_forced_op!($t, BitXor, bitxor, BitXorAssign, bitxor_assign);
+
+		impl<T: Not<Output=T>> Not for $t<T> {
+			type Output = Self;
+
+			fn not(self) -> Self {
+				Self(!self.0)
+			}
+		}
+
+		impl<E: Endianness, R: ERead<E>, T: Deserialize<$e, R>> Deserialize<E, R> for $t<T> {
+			fn deserialize(reader: &mut R) -> Res<Self> {
+				Ok(Self(reader.$read()?))
+			}
+		}
+
+		impl<E: Endianness, W: EWrite<E>, T: Serialize<$e, W>> Serialize<E, W> for $t<T> {
+			fn serialize(self, writer: &mut W) -> Res<()> {
+				writer.$write(self.0)
+			}
+		}
+
+		impl<E: Endianness, W: EWrite<E>, T> Serialize<E, W> for &$t<T> where for<'a> &'a T: Serialize<$e, W> {
+			fn serialize(self, writer: &mut W) -> Res<()> {
+				writer.$write(&self.0)
+			}
+		}
+	}
+}
+
+impl_forced!(Be, BigEndian, read_be, write_be);
+impl_forced!(Le, LittleEndian, read_le, write_le);
+
+macro_rules! impl_forced_into {
+	($($int:ident),*) => {
+		$(
+			impl From<Be<$int>> for $int {
+				fn from(value: Be<$int>) -> Self {
+					value.0
+				}
+			}
+
+			impl From<Le<$int>> for $int {
+				fn from(value: Le<$int>) -> Self {
+					value.0
+				}
+			}
+		)*
+	}
+}
+
+impl_forced_into!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+#[cfg(test)]
+mod tests {
+	use crate::{Be, Le};
+
+	#[test]
+	fn read_forced() {
+		let data = b"\xba\xad\xba\xad";
+		{
+			use crate::LERead;
+			let mut reader = &data[..];
+			let val: Be<u16> = reader.read().unwrap();
+			assert_eq!(val, Be(0xbaad));
+			let val: Le<u16> = reader.read().unwrap();
+			assert_eq!(val, Le(0xadba));
+		}
+		{
+			use crate::BERead;
+			let mut reader = &data[..];
+			let val: (Le<u16>, Be<u16>) = reader.read().unwrap();
+			assert_eq!(val, (Le(0xadba), Be(0xbaad)));
+		}
+	}
+
+	#[test]
+	fn write_forced() {
+		let data = b"\xba\xad\xad\xba\x00\x00\x00\x01";
+		use crate::BEWrite;
+		let mut writer = vec![];
+		writer.write(Be(0xbaadu16)).unwrap();
+		writer.write(Le(0xbaadu16)).unwrap();
+		writer.write(&[Le(0x01000000u32)][..]).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn arithmetic() {
+		let mut a = Be(10u32);
+		a += Be(5);
+		a *= Be(2);
+		assert_eq!(a - Be(1), Be(29));
+		assert_eq!(a / Be(4) % Be(5), Be(2));
+		assert_eq!(!Le(0u8) & Le(0x0f) | Le(0x10) ^ Le(0x01), Le(0x1f));
+		assert_eq!(u32::from(a), 30);
+		assert_eq!(Le::from(3i8).into_inner(), 3);
+	}
+}
@@ -0,0 +1,289 @@
+use core::fmt;
+use core::str::FromStr;
+
+use crate::io::Result as Res;
+use crate::{Deserialize, Endianness, ERead, EWrite, Serialize};
+
+/**
+	A Microsoft GUID, with its first three fields in the stream's endianness.
+
+	The GUID is (de-)serialized as `data1`, `data2` and `data3` in the stream's endianness, followed by the 8 bytes of `data4` as they are. In little endian streams, this is the layout Windows uses in memory and in most file formats. For the fully big endian layout of RFC 4122, use [`Uuid`] instead. The two can be converted into each other without changing the value.
+
+	Formatting and parsing use the usual `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx` form. Parsing is case-insensitive, and also accepts the form enclosed in braces.
+
+	## Examples
+
+	```
+	use endio::{Guid, LERead, LEWrite};
+
+	let mut reader = &b"\x33\x22\x11\x00\x55\x44\x77\x66\x88\x99\xaa\xbb\xcc\xdd\xee\xff"[..];
+	let val: Guid = reader.read().unwrap();
+	assert_eq!(val.to_string(), "00112233-4455-6677-8899-aabbccddeeff");
+
+	let val: Guid = "{00112233-4455-6677-8899-AABBCCDDEEFF}".parse().unwrap();
+	let mut writer = vec![];
+	writer.write(val).unwrap();
+	assert_eq!(writer, b"\x33\x22\x11\x00\x55\x44\x77\x66\x88\x99\xaa\xbb\xcc\xdd\xee\xff");
+	```
+*/
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Guid {
+	/// The first 8 hex digits of the text form. (De-)serialized in the stream's endianness.
+	pub data1: u32,
+	/// The next 4 hex digits of the text form. (De-)serialized in the stream's endianness.
+	pub data2: u16,
+	/// The next 4 hex digits of the text form. (De-)serialized in the stream's endianness.
+	pub data3: u16,
+	/// The last 16 hex digits of the text form, as bytes in the order they appear there. (De-)serialized as they are, regardless of the stream's endianness.
+	pub data4: [u8; 8],
+}
+
+/**
+	An RFC 4122 UUID, stored as 16 bytes in big endian order.
+
+	The UUID is (de-)serialized as its 16 bytes as they are, regardless of the stream's endianness. Formatting and parsing work like for [`Guid`].
+
+	## Examples
+
+	```
+	use endio::{LERead, Uuid};
+
+	let mut reader = &b"\x00\x11\x22\x33\x44\x55\x66\x77\x88\x99\xaa\xbb\xcc\xdd\xee\xff"[..];
+	let val: Uuid = reader.read().unwrap();
+	assert_eq!(val.to_string(), "00112233-4455-6677-8899-aabbccddeeff");
+	```
+*/
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Uuid(pub [u8; 16]);
+
+/// The error returned when parsing a [`Guid`] or [`Uuid`] fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseGuidError;
+
+impl fmt::Display for ParseGuidError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("invalid GUID syntax")
+	}
+}
+
+impl core::error::Error for ParseGuidError {}
+
+impl From<Uuid> for Guid {
+	fn from(uuid: Uuid) -> Self {
+		let b = uuid.0;
+		Self {
+			data1: u32::from_be_bytes([b[0], b[1], b[2], b[3]]),
+			data2: u16::from_be_bytes([b[4], b[5]]),
+			data3: u16::from_be_bytes([b[6], b[7]]),
+			data4: [b[8], b[9], b[10], b[11], b[12], b[13], b[14], b[15]],
+		}
+	}
+}
+
+impl From<Guid> for Uuid {
+	fn from(guid: Guid) -> Self {
+		let mut b = [0; 16];
+		b[..4].copy_from_slice(&guid.data1.to_be_bytes());
+		b[4..6].copy_from_slice(&guid.data2.to_be_bytes());
+		b[6..8].copy_from_slice(&guid.data3.to_be_bytes());
+		b[8..].copy_from_slice(&guid.data4);
+		Self(b)
+	}
+}
+
+impl fmt::Display for Uuid {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		for (i, byte) in self.0.iter().enumerate() {
+			if let 4 | 6 | 8 | 10 = i {
+				f.write_str("-")?;
+			}
+			write!(f, "{:02x}", byte)?;
+		}
+		Ok(())
+	}
+}
+
+impl fmt::Display for Guid {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt::Display::fmt(&Uuid::from(*self), f)
+	}
+}
+
+impl FromStr for Uuid {
+	type Err = ParseGuidError;
+
+	fn from_str(s: &str) -> Result<Self, ParseGuidError> {
+		let s = s.strip_prefix('{').and_then(|s| s.strip_suffix('}')).unwrap_or(s);
+		let s = s.as_bytes();
+		if s.len() != 36 {
+			return Err(ParseGuidError);
+		}
+		let mut bytes = [0; 16];
+		let mut digits = s.iter().enumerate().filter(|(i, _)| !matches!(i, 8 | 13 | 18 | 23));
+		for byte in bytes.iter_mut() {
+			for _ in 0..2 {
+				let (_, digit) = digits.next().ok_or(ParseGuidError)?;
+				let digit = (*digit as char).to_digit(16).ok_or(ParseGuidError)?;
+				*byte = *byte << 4 | digit as u8;
+			}
+		}
+		if [8, 13, 18, 23].iter().any(|i| s[*i] != b'-') {
+			return Err(ParseGuidError);
+		}
+		Ok(Self(bytes))
+	}
+}
+
+impl FromStr for Guid {
+	type Err = ParseGuidError;
+
+	fn from_str(s: &str) -> Result<Self, ParseGuidError> {
+		s.parse::<Uuid>().map(Self::from)
+	}
+}
+
+/// Reads `data1`, `data2` and `data3` in the stream's endianness, then reads the bytes of `data4`.
+impl<E: Endianness, R: ERead<E>> Deserialize<E, R> for Guid
+	where u32: Deserialize<E, R>,
+	      u16: Deserialize<E, R>,
+	      [u8; 8]: Deserialize<E, R> {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		Ok(Self {
+			data1: reader.read()?,
+			data2: reader.read()?,
+			data3: reader.read()?,
+			data4: reader.read()?,
+		})
+	}
+}
+
+/// Writes `data1`, `data2` and `data3` in the stream's endianness, then writes the bytes of `data4`.
+impl<E: Endianness, W: EWrite<E>> Serialize<E, W> for Guid
+	where u32: Serialize<E, W>,
+	      u16: Serialize<E, W>,
+	      for<'a> &'a [u8; 8]: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		writer.write(self.data1)?;
+		writer.write(self.data2)?;
+		writer.write(self.data3)?;
+		writer.write(&self.data4)
+	}
+}
+
+/// Reads the 16 bytes of the UUID.
+impl<E: Endianness, R: ERead<E>> Deserialize<E, R> for Uuid where [u8; 16]: Deserialize<E, R> {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		Ok(Self(reader.read()?))
+	}
+}
+
+/// Writes the 16 bytes of the UUID.
+impl<E: Endianness, W: EWrite<E>> Serialize<E, W> for Uuid where for<'a> &'a [u8; 16]: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		writer.write(&self.0)
+	}
+}
+
+impl<E: Endianness, W: EWrite<E>> Serialize<E, W> for &Guid where Guid: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		writer.write(*self)
+	}
+}
+
+impl<E: Endianness, W: EWrite<E>> Serialize<E, W> for &Uuid where Uuid: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		writer.write(*self)
+	}
+}
+
+#[cfg(feature="uuid")]
+impl From<uuid::Uuid> for Uuid {
+	fn from(uuid: uuid::Uuid) -> Self {
+		Self(uuid.into_bytes())
+	}
+}
+
+#[cfg(feature="uuid")]
+impl From<Uuid> for uuid::Uuid {
+	fn from(uuid: Uuid) -> Self {
+		Self::from_bytes(uuid.0)
+	}
+}
+
+#[cfg(feature="uuid")]
+impl From<uuid::Uuid> for Guid {
+	fn from(uuid: uuid::Uuid) -> Self {
+		Uuid::from(uuid).into()
+	}
+}
+
+#[cfg(feature="uuid")]
+impl From<Guid> for uuid::Uuid {
+	fn from(guid: Guid) -> Self {
+		Uuid::from(guid).into()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{Guid, ParseGuidError, Uuid};
+
+	const GUID: Guid = Guid { data1: 0x00112233, data2: 0x4455, data3: 0x6677, data4: [0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff] };
+
+	#[test]
+	fn read_guid() {
+		{
+			use crate::LERead;
+			let mut reader = &b"\x33\x22\x11\x00\x55\x44\x77\x66\x88\x99\xaa\xbb\xcc\xdd\xee\xff"[..];
+			let val: Guid = reader.read().unwrap();
+			assert_eq!(val, GUID);
+		}
+		{
+			use crate::BERead;
+			let mut reader = &b"\x00\x11\x22\x33\x44\x55\x66\x77\x88\x99\xaa\xbb\xcc\xdd\xee\xff"[..];
+			let val: Guid = reader.read().unwrap();
+			assert_eq!(val, GUID);
+		}
+	}
+
+	#[test]
+	fn write_uuid() {
+		let data = b"\x00\x11\x22\x33\x44\x55\x66\x77\x88\x99\xaa\xbb\xcc\xdd\xee\xff";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(Uuid::from(GUID)).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn conversion() {
+		assert_eq!(Guid::from(Uuid::from(GUID)), GUID);
+		assert_eq!(Uuid::from(GUID).0[..4], [0x00, 0x11, 0x22, 0x33]);
+	}
+
+	#[test]
+	fn format() {
+		assert_eq!(GUID.to_string(), "00112233-4455-6677-8899-aabbccddeeff");
+		assert_eq!(Uuid::default().to_string(), "00000000-0000-0000-0000-000000000000");
+	}
+
+	#[test]
+	fn parse() {
+		assert_eq!("00112233-4455-6677-8899-aabbccddeeff".parse(), Ok(GUID));
+		assert_eq!("{00112233-4455-6677-8899-AABBCCDDEEFF}".parse(), Ok(GUID));
+		assert_eq!("00112233-4455-6677-8899-aabbccddeeff".parse(), Ok(Uuid::from(GUID)));
+		assert_eq!("00112233-4455-6677-8899-aabbccddeef".parse::<Guid>(), Err(ParseGuidError));
+		assert_eq!("00112233-4455-6677-8899-aabbccddeefg".parse::<Guid>(), Err(ParseGuidError));
+		assert_eq!("00112233-4455-6677-88990aabbccddeeff".parse::<Guid>(), Err(ParseGuidError));
+		assert_eq!("{00112233-4455-6677-8899-aabbccddeeff".parse::<Guid>(), Err(ParseGuidError));
+		assert_eq!("+0112233-4455-6677-8899-aabbccddeeff".parse::<Guid>(), Err(ParseGuidError));
+	}
+
+	#[cfg(feature="uuid")]
+	#[test]
+	fn uuid_interop() {
+		let uuid = uuid::Uuid::parse_str("00112233-4455-6677-8899-aabbccddeeff").unwrap();
+		assert_eq!(Guid::from(uuid), GUID);
+		assert_eq!(uuid::Uuid::from(GUID), uuid);
+	}
+}
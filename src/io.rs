@@ -0,0 +1,222 @@
+#[cfg(not(feature="std"))]
+use alloc::borrow::Cow;
+#[cfg(not(feature="std"))]
+use alloc::vec::Vec;
+#[cfg(not(feature="std"))]
+use core::fmt;
+
+#[cfg(feature="std")]
+pub use std::io::{Error, ErrorKind, Result};
+
+/**
+	A list specifying general categories of I/O error.
+
+	With the `std` feature, this is `std::io::ErrorKind`. Without it, only the kinds used by this crate are available.
+*/
+#[cfg(not(feature="std"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+	/// A parameter was incorrect, e.g. a value that can't be represented in the binary format.
+	InvalidInput,
+	/// Data read was not valid for the type being read.
+	InvalidData,
+	/// The reader ran out of bytes before the value was complete.
+	UnexpectedEof,
+	/// The writer ran out of space before the value was complete.
+	WriteZero,
+	/// Any other error.
+	Other,
+}
+
+#[cfg(not(feature="std"))]
+impl ErrorKind {
+	fn as_str(self) -> &'static str {
+		match self {
+			ErrorKind::InvalidInput => "invalid input parameter",
+			ErrorKind::InvalidData => "invalid data",
+			ErrorKind::UnexpectedEof => "unexpected end of file",
+			ErrorKind::WriteZero => "write zero",
+			ErrorKind::Other => "other error",
+		}
+	}
+}
+
+#[cfg(not(feature="std"))]
+impl fmt::Display for ErrorKind {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+/**
+	The error type for reading and writing.
+
+	With the `std` feature, this is `std::io::Error`, so errors from the underlying reader or writer are passed through unchanged. Without it, this is an error kind with a message, with the same `new` and `kind` methods as `std::io::Error`.
+*/
+#[cfg(not(feature="std"))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error {
+	kind: ErrorKind,
+	message: Cow<'static, str>,
+}
+
+#[cfg(not(feature="std"))]
+impl Error {
+	/// Creates an error of the given kind with a message.
+	pub fn new<M: Into<Cow<'static, str>>>(kind: ErrorKind, message: M) -> Self {
+		Self { kind, message: message.into() }
+	}
+
+	/// Returns the kind of the error.
+	pub fn kind(&self) -> ErrorKind {
+		self.kind
+	}
+}
+
+#[cfg(not(feature="std"))]
+impl From<ErrorKind> for Error {
+	fn from(kind: ErrorKind) -> Self {
+		Self::new(kind, kind.as_str())
+	}
+}
+
+#[cfg(not(feature="std"))]
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(&self.message)
+	}
+}
+
+#[cfg(not(feature="std"))]
+impl core::error::Error for Error {}
+
+/// A specialized `Result` type for reading and writing.
+#[cfg(not(feature="std"))]
+pub type Result<T> = core::result::Result<T, Error>;
+
+/**
+	A source of bytes.
+
+	This is the minimal interface the `read` traits need. With the `std` feature (enabled by default), it is implemented for every `std::io::Read`. Without it, it is implemented for `&[u8]`, and you can implement it for your own types.
+
+	## Examples
+
+	```
+	use endio::{ByteRead, LERead};
+
+	let mut reader = &b"\x2a\xba\xad"[..];
+	let mut buf = [0; 1];
+	ByteRead::read_exact(&mut reader, &mut buf).unwrap();
+	assert_eq!(buf, [42]);
+	let val: u16 = reader.read().unwrap();
+	assert_eq!(val, 0xadba);
+	```
+*/
+pub trait ByteRead {
+	/// Reads exactly enough bytes to fill `buf`, returning an `UnexpectedEof` error if there aren't enough.
+	fn read_exact(&mut self, buf: &mut [u8]) -> Result<()>;
+}
+
+/**
+	A sink for bytes.
+
+	This is the minimal interface the `write` traits need. With the `std` feature (enabled by default), it is implemented for every `std::io::Write`. Without it, it is implemented for `&mut [u8]` and `Vec<u8>`, and you can implement it for your own types.
+*/
+pub trait ByteWrite {
+	/// Writes all of `buf`, returning a `WriteZero` error if there isn't enough space.
+	fn write_all(&mut self, buf: &[u8]) -> Result<()>;
+}
+
+#[cfg(feature="std")]
+impl<R: std::io::Read + ?Sized> ByteRead for R {
+	fn read_exact(&mut self, buf: &mut [u8]) -> Result<()> {
+		std::io::Read::read_exact(self, buf)
+	}
+}
+
+#[cfg(feature="std")]
+impl<W: std::io::Write + ?Sized> ByteWrite for W {
+	fn write_all(&mut self, buf: &[u8]) -> Result<()> {
+		std::io::Write::write_all(self, buf)
+	}
+}
+
+/// Reads from the front of the slice, advancing it past the bytes read.
+#[cfg(not(feature="std"))]
+impl ByteRead for &[u8] {
+	fn read_exact(&mut self, buf: &mut [u8]) -> Result<()> {
+		if self.len() < buf.len() {
+			*self = &self[self.len()..];
+			return Err(Error::new(ErrorKind::UnexpectedEof, "failed to fill whole buffer"));
+		}
+		let (bytes, rest) = self.split_at(buf.len());
+		buf.copy_from_slice(bytes);
+		*self = rest;
+		Ok(())
+	}
+}
+
+/// Writes to the front of the slice, advancing it past the bytes written.
+#[cfg(not(feature="std"))]
+impl ByteWrite for &mut [u8] {
+	fn write_all(&mut self, buf: &[u8]) -> Result<()> {
+		if self.len() < buf.len() {
+			return Err(Error::new(ErrorKind::WriteZero, "failed to write whole buffer"));
+		}
+		let (bytes, rest) = core::mem::take(self).split_at_mut(buf.len());
+		bytes.copy_from_slice(buf);
+		*self = rest;
+		Ok(())
+	}
+}
+
+/// Appends to the Vec.
+#[cfg(not(feature="std"))]
+impl ByteWrite for Vec<u8> {
+	fn write_all(&mut self, buf: &[u8]) -> Result<()> {
+		self.extend_from_slice(buf);
+		Ok(())
+	}
+}
+
+#[cfg(not(feature="std"))]
+impl<R: ByteRead + ?Sized> ByteRead for &mut R {
+	fn read_exact(&mut self, buf: &mut [u8]) -> Result<()> {
+		(**self).read_exact(buf)
+	}
+}
+
+#[cfg(not(feature="std"))]
+impl<W: ByteWrite + ?Sized> ByteWrite for &mut W {
+	fn write_all(&mut self, buf: &[u8]) -> Result<()> {
+		(**self).write_all(buf)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::io;
+
+	#[test]
+	fn read_slice_eof() {
+		use crate::LERead;
+		let mut reader = &b"\xba\xad\xf0"[..];
+		let val: u16 = reader.read().unwrap();
+		assert_eq!(val, 0xadba);
+		let err = reader.read::<u16>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+	}
+
+	#[test]
+	fn write_slice() {
+		use crate::BEWrite;
+		let mut buf = [0; 3];
+		let mut writer = &mut buf[..];
+		writer.write(0xbaadu16).unwrap();
+		assert_eq!(writer.len(), 1);
+		let err = writer.write(0xf00du16).unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+		assert_eq!(&buf[..2], b"\xba\xad");
+	}
+}
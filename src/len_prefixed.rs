@@ -0,0 +1,325 @@
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+
+use crate::io;
+use crate::io::Result as Res;
+use crate::{ByteRead, Deserialize, Endianness, ERead, EWrite, RejectDuplicates, Serialize};
+
+/// Upper bound for preallocating space based on a length read from the input, so that corrupt or malicious lengths can't make us allocate huge amounts of memory up front.
+pub(crate) const MAX_PREALLOC: usize = 4096;
+
+/**
+	A collection preceded by its length.
+
+	The length is (de-)serialized as `L` in the stream's endianness, followed by that many elements of the collection. `L` can be any integer type (or any other type that is convertible from and to `usize`).
+
+	Maps and sets are (de-)serialized as their number of entries, followed by the entries. For maps, each entry is (de-)serialized as its key followed by its value. `D` is the [`DuplicatePolicy`](crate::DuplicatePolicy) that decides what happens when a key is read that is already present. It only applies to maps and sets, and must be left at its default for all other collections.
+
+	When writing, an `InvalidInput` error is returned if the length of the collection does not fit in `L`. When reading, an `InvalidData` error is returned if the length read does not fit in `usize`, e.g. because it is negative.
+
+	## Examples
+
+	```
+	use endio::{LERead, LEWrite, LenPrefixed};
+
+	let mut reader = &b"\x02\x00\xba\xad\xf0\x0d"[..];
+	let val: LenPrefixed<u16, Vec<u16>> = reader.read().unwrap();
+	assert_eq!(*val, vec![0xadba, 0x0df0]);
+
+	let mut writer = vec![];
+	writer.write(&val).unwrap();
+	assert_eq!(writer, b"\x02\x00\xba\xad\xf0\x0d");
+	```
+
+	Slices can be written without having to copy them into a `Vec` first:
+
+	```
+	use endio::{BEWrite, LenPrefixed};
+
+	let mut writer = vec![];
+	writer.write(LenPrefixed::<u8, _>::new(&[0xbaadu16, 0xf00d][..])).unwrap();
+	assert_eq!(writer, b"\x02\xba\xad\xf0\x0d");
+	```
+
+	When reading from a byte slice, `LenPrefixed<L, &[u8]>` borrows the bytes from the input instead of copying them:
+
+	```
+	use endio::{LERead, LenPrefixed};
+
+	let data = b"\x02\x00\xba\xad\xf0\x0d";
+	let mut reader = &data[..];
+	let val: LenPrefixed<u16, &[u8]> = reader.read().unwrap();
+	assert_eq!(*val, b"\xba\xad");
+	assert_eq!(reader, b"\xf0\x0d");
+	```
+*/
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct LenPrefixed<L, T, D = RejectDuplicates> {
+	inner: T,
+	len_type: PhantomData<L>,
+	policy: PhantomData<D>,
+}
+
+impl<L, T, D> LenPrefixed<L, T, D> {
+	/// Wraps the collection.
+	pub fn new(inner: T) -> Self {
+		Self { inner, len_type: PhantomData, policy: PhantomData }
+	}
+
+	/// Unwraps the collection.
+	pub fn into_inner(self) -> T {
+		self.inner
+	}
+}
+
+impl<L, T, D> From<T> for LenPrefixed<L, T, D> {
+	fn from(inner: T) -> Self {
+		Self::new(inner)
+	}
+}
+
+impl<L, T, D> Deref for LenPrefixed<L, T, D> {
+	type Target = T;
+
+	fn deref(&self) -> &T {
+		&self.inner
+	}
+}
+
+impl<L, T, D> DerefMut for LenPrefixed<L, T, D> {
+	fn deref_mut(&mut self) -> &mut T {
+		&mut self.inner
+	}
+}
+
+/// Reads a length of type `L` and converts it to `usize`, returning an `InvalidData` error if it doesn't fit.
+pub(crate) fn read_len<E: Endianness, R: ERead<E>, L>(reader: &mut R) -> Res<usize>
+	where L: Deserialize<E, R>,
+	      usize: TryFrom<L> {
+	let len: L = reader.read()?;
+	usize::try_from(len).map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "length prefix does not fit in usize"))
+}
+
+/// Converts a length to `L` and writes it, returning an `InvalidInput` error if it doesn't fit.
+pub(crate) fn write_len<E: Endianness, W: EWrite<E>, L>(writer: &mut W, len: usize) -> Res<()>
+	where L: Serialize<E, W> + TryFrom<usize> {
+	let len = L::try_from(len).map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "length does not fit in length prefix type"))?;
+	writer.write(len)
+}
+
+/// Reads exactly `len` bytes, without trusting `len` for preallocation. Lengths up to `MAX_PREALLOC` are read with a single read, larger ones grow the buffer geometrically as data arrives.
+pub(crate) fn read_bytes<R: ByteRead>(reader: &mut R, len: usize) -> Res<Vec<u8>> {
+	let mut buf = Vec::new();
+	while buf.len() < len {
+		let start = buf.len();
+		buf.resize(start + (len - start).min(start.max(MAX_PREALLOC)), 0);
+		reader.read_exact(&mut buf[start..])?;
+	}
+	Ok(buf)
+}
+
+/// Splits `len` bytes off the front of a slice reader, returning an `UnexpectedEof` error if there aren't enough left.
+pub(crate) fn split_bytes<'a>(reader: &mut &'a [u8], len: usize) -> Res<&'a [u8]> {
+	if reader.len() < len {
+		return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "failed to fill whole buffer"));
+	}
+	let (bytes, rest) = reader.split_at(len);
+	*reader = rest;
+	Ok(bytes)
+}
+
+/// Reads a length of type `L`, then reads that many elements.
+impl<E: Endianness, R: ERead<E>, L, T> Deserialize<E, R> for LenPrefixed<L, Vec<T>>
+	where L: Deserialize<E, R>,
+	      usize: TryFrom<L>,
+	      T: Deserialize<E, R> {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let len = read_len::<E, R, L>(reader)?;
+		Ok(Self::new(T::deserialize_vec(reader, len)?))
+	}
+}
+
+/// Reads a length of type `L`, then borrows that many bytes from the input without copying them.
+impl<'a, E: Endianness, L> Deserialize<E, &'a [u8]> for LenPrefixed<L, &'a [u8]>
+	where L: Deserialize<E, &'a [u8]>,
+	      usize: TryFrom<L> {
+	fn deserialize(reader: &mut &'a [u8]) -> Res<Self> {
+		let len = read_len::<E, &'a [u8], L>(reader)?;
+		Ok(Self::new(split_bytes(reader, len)?))
+	}
+}
+
+/// Writes the length of the slice as `L`, then writes the elements.
+impl<E: Endianness, W: EWrite<E>, L, S> Serialize<E, W> for LenPrefixed<L, &[S]>
+	where L: Serialize<E, W> + TryFrom<usize>,
+	      for<'a> &'a S: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		write_len::<E, W, L>(writer, self.inner.len())?;
+		writer.write(self.inner)
+	}
+}
+
+/// Writes the length of the slice as `L`, then writes the elements.
+impl<E: Endianness, W: EWrite<E>, L, S> Serialize<E, W> for &LenPrefixed<L, &[S]>
+	where L: Serialize<E, W> + TryFrom<usize>,
+	      for<'a> &'a S: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		write_len::<E, W, L>(writer, self.inner.len())?;
+		writer.write(self.inner)
+	}
+}
+
+/// Writes the length of the Vec as `L`, then writes the elements.
+impl<E: Endianness, W: EWrite<E>, L, S> Serialize<E, W> for &LenPrefixed<L, Vec<S>>
+	where L: Serialize<E, W> + TryFrom<usize>,
+	      for<'a> &'a S: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		write_len::<E, W, L>(writer, self.inner.len())?;
+		writer.write(self.inner.as_slice())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::io;
+	use crate::LenPrefixed;
+
+	#[test]
+	fn read_vec() {
+		let data = b"\x00\x02\xba\xad\xf0\x0d";
+		use crate::BERead;
+		let mut reader = &data[..];
+		let val: LenPrefixed<u16, Vec<u16>> = reader.read().unwrap();
+		assert_eq!(*val, vec![0xbaad, 0xf00d]);
+	}
+
+	#[test]
+	fn read_vec_empty() {
+		let data = b"\x00\x00\x00\x00";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let val: LenPrefixed<u32, Vec<u8>> = reader.read().unwrap();
+		assert!(val.is_empty());
+	}
+
+	#[test]
+	fn read_vec_negative_len() {
+		let data = b"\xff";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let err = reader.read::<LenPrefixed<i8, Vec<u8>>>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+	}
+
+	#[test]
+	fn read_vec_eof() {
+		let data = b"\x03\xba\xad";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let err = reader.read::<LenPrefixed<u8, Vec<u8>>>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+	}
+
+	#[test]
+	fn read_vec_large() {
+		let mut data = vec![0x00, 0x00, 0x27, 0x10];
+		for i in 0..10000u32 {
+			data.extend_from_slice(&i.to_be_bytes());
+		}
+		use crate::BERead;
+		let mut reader = &data[..];
+		let val: LenPrefixed<u32, Vec<u32>> = reader.read().unwrap();
+		assert_eq!(*val, (0..10000).collect::<Vec<_>>());
+		assert!(reader.is_empty());
+	}
+
+	#[test]
+	fn read_vec_large_eof() {
+		let data = b"\xff\xff\xff\x7f\xba\xad";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let err = reader.read::<LenPrefixed<u32, Vec<u16>>>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+	}
+
+	#[test]
+	fn read_vec_float() {
+		let data = b"\x02\x00\x00\x00\x00\x00\x00\xf0\x3f\x00\x00\x00\x00\x00\x00\x00\xc0";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let val: LenPrefixed<u8, Vec<f64>> = reader.read().unwrap();
+		assert_eq!(*val, vec![1.0, -2.0]);
+	}
+
+	#[test]
+	#[cfg(feature="std")]
+	fn read_vec_single_call() {
+		struct CountingReader<'a>(&'a [u8], usize);
+
+		impl std::io::Read for CountingReader<'_> {
+			fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+				self.1 += 1;
+				std::io::Read::read(&mut self.0, buf)
+			}
+		}
+
+		let mut data = vec![0xe8, 0x03];
+		for i in 0..1000i16 {
+			data.extend_from_slice(&i.to_le_bytes());
+		}
+		use crate::LERead;
+		let mut reader = CountingReader(&data, 0);
+		let val: LenPrefixed<u16, Vec<i16>> = reader.read().unwrap();
+		assert_eq!(*val, (0..1000).collect::<Vec<_>>());
+		assert_eq!(reader.1, 2);
+	}
+
+	#[test]
+	fn read_borrowed_slice() {
+		let data = b"\x02\xba\xad\xf0";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let val: LenPrefixed<u8, &[u8]> = reader.read().unwrap();
+		assert_eq!(*val, b"\xba\xad");
+		assert!(std::ptr::eq(val.as_ptr(), data[1..].as_ptr()));
+		assert_eq!(reader, b"\xf0");
+	}
+
+	#[test]
+	fn read_borrowed_slice_eof() {
+		let data = b"\x00\x03\xba\xad";
+		use crate::BERead;
+		let mut reader = &data[..];
+		let err = reader.read::<LenPrefixed<u16, &[u8]>>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+	}
+
+	#[test]
+	fn write_vec() {
+		let data = b"\x02\x00\xba\xad\xf0\x0d";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(&LenPrefixed::<u16, _>::new(vec![0xadbau16, 0x0df0])).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn write_slice() {
+		let data = b"\x00\x00\x00\x02\xba\xad";
+		use crate::BEWrite;
+		let mut writer = vec![];
+		writer.write(LenPrefixed::<u32, _>::new(&b"\xba\xad"[..])).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn write_len_too_large() {
+		use crate::LEWrite;
+		let mut writer = vec![];
+		let err = writer.write(&LenPrefixed::<u8, _>::new(vec![0u8; 256])).unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+		assert!(writer.is_empty());
+	}
+}
@@ -117,23 +117,76 @@
 
 	## Getting started
 
-	To conduct I/O you `use` the traits `BERead` & `BEWrite`, or `LERead` & `LEWrite`. Choose `BERead` & `BEWrite` for big endian I/O, and `LERead` & `LEWrite` for little endian I/O. This will give you the `read`/`write` methods on your structs. `read` returns values of your desired type, and `write` accepts values as a parameter. The deserialization to be used and the type to be returned are handled through type inference, so most of the time you won't even need to annotate the type explicitly.
+	To conduct I/O you `use` the traits `BERead` & `BEWrite`, or `LERead` & `LEWrite`. Choose `BERead` & `BEWrite` for big endian I/O, and `LERead` & `LEWrite` for little endian I/O. If you need the native endianness of the platform you're running on, choose `NERead` & `NEWrite`. This will give you the `read`/`write` methods on your structs. `read` returns values of your desired type, and `write` accepts values as a parameter. The deserialization to be used and the type to be returned are handled through type inference, so most of the time you won't even need to annotate the type explicitly.
 
 	You can read and write your own types by implementing `Serialize`/`Deserialize`. See their documentation for details.
+
+	### `no_std` support
+
+	Disable the default `std` feature to use this crate without the standard library. Only `alloc` is required. Reading and writing then works on the minimal [`ByteRead`] and [`ByteWrite`] traits of this crate instead of `std::io::{Read, Write}`. They are implemented for `&[u8]`, `&mut [u8]` and `Vec<u8>`, and errors are this crate's own [`Error`] type.
+
+	With `std`, every `Read`/`Write` implements `ByteRead`/`ByteWrite`, and [`Error`] is `std::io::Error`. Code using `ByteRead`, `ByteWrite` and `Error` therefore works in both modes, and so do the derive macros. The `SystemTime`-based timestamps and the impls for `HashMap` and `HashSet` are only available with `std`.
 */
+#![cfg_attr(not(any(feature="std", test)), no_std)]
+// The docs are indented with tabs, and the original tests predate these lints.
+#![allow(clippy::tabs_in_doc_comments)]
+#![cfg_attr(test, allow(clippy::bool_assert_comparison, clippy::excessive_precision, clippy::legacy_numeric_constants, clippy::needless_late_init, clippy::redundant_slicing))]
 
+extern crate alloc;
+
+mod io;
 mod endian;
 mod read;
 mod write;
 mod deserialize;
 mod serialize;
-
+mod len_prefixed;
+mod collections;
+mod string;
+mod wstring;
+mod net;
+mod result;
+mod odd_int;
+mod float16;
+mod fixed;
+mod varint;
+mod boolean;
+mod option;
+mod guid;
+#[cfg(feature="std")]
+mod time;
+mod dyn_endian;
+mod forced;
+
+pub use self::io::{ByteRead, ByteWrite, Error, ErrorKind};
 pub use self::endian::*;
 pub use self::read::*;
 pub use self::write::*;
 pub use self::deserialize::*;
 pub use self::serialize::*;
+pub use self::len_prefixed::*;
+pub use self::collections::*;
+pub use self::string::*;
+pub use self::wstring::*;
+pub use self::net::*;
+pub use self::result::*;
+pub use self::odd_int::*;
+pub use self::float16::*;
+pub use self::fixed::*;
+pub use self::varint::*;
+pub use self::boolean::*;
+pub use self::option::*;
+pub use self::guid::*;
+#[cfg(feature="std")]
+pub use self::time::*;
+pub use self::dyn_endian::*;
+pub use self::forced::*;
 
 #[cfg(feature="derive")]
 pub use endio_derive::*;
 
+#[doc(hidden)]
+pub mod __private {
+	pub use alloc::format;
+}
+
@@ -0,0 +1,190 @@
+#[cfg(feature="std")]
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+#[cfg(not(feature="std"))]
+use core::net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+use crate::io;
+use crate::io::Result as Res;
+use crate::{BigEndian, Deserialize, Endianness, ERead, EWrite, Serialize};
+
+/**
+	A socket address with its port forced to big endian, as in the sockets API.
+
+	By default, the port of a socket address is (de-)serialized in the stream's endianness, like any other integer. Wrap the socket address in this type to always (de-)serialize the port in big endian (network byte order) instead. The flowinfo and scope id of a `SocketAddrV6` are still (de-)serialized in the stream's endianness.
+
+	## Examples
+
+	```
+	use std::net::{Ipv4Addr, SocketAddrV4};
+	use endio::{BEPort, LERead, LEWrite};
+
+	let mut reader = &b"\x7f\x00\x00\x01\x1f\x90"[..];
+	let val: BEPort<SocketAddrV4> = reader.read().unwrap();
+	assert_eq!(val.0, SocketAddrV4::new(Ipv4Addr::LOCALHOST, 8080));
+
+	let mut writer = vec![];
+	writer.write(val).unwrap();
+	assert_eq!(writer, b"\x7f\x00\x00\x01\x1f\x90");
+	```
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BEPort<A>(pub A);
+
+impl<A> From<A> for BEPort<A> {
+	fn from(addr: A) -> Self {
+		Self(addr)
+	}
+}
+
+impl<E: Endianness, R: ERead<E>> Deserialize<E, R> for BEPort<SocketAddrV4>
+	where u16: Deserialize<BigEndian, R>,
+	      Ipv4Addr: Deserialize<E, R> {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let ip = reader.read()?;
+		let port = reader.read_be()?;
+		Ok(Self(SocketAddrV4::new(ip, port)))
+	}
+}
+
+impl<E: Endianness, R: ERead<E>> Deserialize<E, R> for BEPort<SocketAddrV6>
+	where u16: Deserialize<BigEndian, R>,
+	      u32: Deserialize<E, R>,
+	      Ipv6Addr: Deserialize<E, R> {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let ip = reader.read()?;
+		let port = reader.read_be()?;
+		let flowinfo = reader.read()?;
+		let scope_id = reader.read()?;
+		Ok(Self(SocketAddrV6::new(ip, port, flowinfo, scope_id)))
+	}
+}
+
+/// Reads a `u8` tag of 4 or 6, followed by the socket address. Returns an `InvalidData` error for any other tag.
+impl<E: Endianness, R: ERead<E>> Deserialize<E, R> for BEPort<SocketAddr>
+	where u8: Deserialize<E, R>,
+	      BEPort<SocketAddrV4>: Deserialize<E, R>,
+	      BEPort<SocketAddrV6>: Deserialize<E, R> {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let tag: u8 = reader.read()?;
+		match tag {
+			4 => Ok(Self(SocketAddr::V4(reader.read::<BEPort<_>>()?.0))),
+			6 => Ok(Self(SocketAddr::V6(reader.read::<BEPort<_>>()?.0))),
+			_ => Err(io::Error::new(io::ErrorKind::InvalidData, "SocketAddr had tag other than 4 or 6")),
+		}
+	}
+}
+
+impl<E: Endianness, W: EWrite<E>> Serialize<E, W> for BEPort<SocketAddrV4>
+	where u16: Serialize<BigEndian, W>,
+	      Ipv4Addr: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		writer.write(*self.0.ip())?;
+		writer.write_be(self.0.port())
+	}
+}
+
+impl<E: Endianness, W: EWrite<E>> Serialize<E, W> for BEPort<SocketAddrV6>
+	where u16: Serialize<BigEndian, W>,
+	      u32: Serialize<E, W>,
+	      Ipv6Addr: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		writer.write(*self.0.ip())?;
+		writer.write_be(self.0.port())?;
+		writer.write(self.0.flowinfo())?;
+		writer.write(self.0.scope_id())
+	}
+}
+
+/// Writes a `u8` tag of 4 or 6, followed by the socket address.
+impl<E: Endianness, W: EWrite<E>> Serialize<E, W> for BEPort<SocketAddr>
+	where u8: Serialize<E, W>,
+	      BEPort<SocketAddrV4>: Serialize<E, W>,
+	      BEPort<SocketAddrV6>: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		match self.0 {
+			SocketAddr::V4(addr) => {
+				writer.write(4u8)?;
+				writer.write(BEPort(addr))
+			}
+			SocketAddr::V6(addr) => {
+				writer.write(6u8)?;
+				writer.write(BEPort(addr))
+			}
+		}
+	}
+}
+
+impl<E: Endianness, W: EWrite<E>, A: Copy> Serialize<E, W> for &BEPort<A> where BEPort<A>: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		writer.write(*self)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+	use crate::BEPort;
+
+	#[test]
+	fn read_socket_addr_v4() {
+		let data = b"\x01\x00\x00\x7f\x1f\x90";
+		let mut val: BEPort<SocketAddrV4>;
+		{
+			use crate::BERead;
+			let mut reader = &data[..];
+			val = reader.read().unwrap();
+			assert_eq!(val.0, SocketAddrV4::new(Ipv4Addr::new(1, 0, 0, 127), 8080));
+		}
+		{
+			use crate::LERead;
+			let mut reader = &data[..];
+			val = reader.read().unwrap();
+			assert_eq!(val.0, SocketAddrV4::new(Ipv4Addr::new(1, 0, 0, 127), 8080));
+		}
+	}
+
+	#[test]
+	fn read_socket_addr_v6() {
+		let data = b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01\x1f\x90\x01\x00\x00\x00\x02\x00\x00\x00";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let val: BEPort<SocketAddrV6> = reader.read().unwrap();
+		assert_eq!(val.0, SocketAddrV6::new(Ipv6Addr::LOCALHOST, 8080, 1, 2));
+	}
+
+	#[test]
+	fn read_socket_addr() {
+		let data = b"\x04\x7f\x00\x00\x01\x1f\x90";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let val: BEPort<SocketAddr> = reader.read().unwrap();
+		assert_eq!(val.0, SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 8080)));
+	}
+
+	#[test]
+	fn write_socket_addr_v4() {
+		let data = b"\x7f\x00\x00\x01\x1f\x90";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(BEPort(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 8080))).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn write_socket_addr_v6() {
+		let data = b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01\x1f\x90\x01\x00\x00\x00\x02\x00\x00\x00";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(BEPort(SocketAddrV6::new(Ipv6Addr::LOCALHOST, 8080, 1, 2))).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn write_socket_addr() {
+		let data = b"\x04\x7f\x00\x00\x01\x1f\x90";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(BEPort(SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 8080)))).unwrap();
+		assert_eq!(writer, data);
+	}
+}
@@ -0,0 +1,181 @@
+use core::mem::size_of;
+
+use crate::io;
+use crate::io::Result as Res;
+use crate::{BigEndian, ByteRead, ByteWrite, Deserialize, Endianness, LittleEndian, Serialize};
+
+macro_rules! impl_odd_int {
+	($t:ident, $int:ident, $bytes:literal, $min:expr, $max:expr, $doc:literal) => {
+		#[doc = $doc]
+		///
+		/// The value is stored in the next larger primitive integer type. Reading sign-extends signed values. Writing returns an `InvalidInput` error if the value is outside of the range `MIN..=MAX`.
+		#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+		pub struct $t(pub $int);
+
+		impl $t {
+			/// The smallest value that can be represented by this integer type.
+			pub const MIN: $int = $min;
+			/// The largest value that can be represented by this integer type.
+			pub const MAX: $int = $max;
+
+			fn to_bytes_checked(self, to_bytes: fn($int) -> [u8; size_of::<$int>()]) -> Res<[u8; size_of::<$int>()]> {
+				if self.0 < Self::MIN || self.0 > Self::MAX {
+					return Err(io::Error::new(io::ErrorKind::InvalidInput, concat!("value does not fit in ", stringify!($t))));
+				}
+				Ok(to_bytes(self.0))
+			}
+
+			fn sign_extend(value: $int) -> $int {
+				const SHIFT: u32 = ((size_of::<$int>() - $bytes) * 8) as u32;
+				(value << SHIFT) >> SHIFT
+			}
+		}
+
+		impl From<$t> for $int {
+			fn from(value: $t) -> Self {
+				value.0
+			}
+		}
+
+		impl<R: ByteRead> Deserialize<BigEndian, R> for $t {
+			fn deserialize(reader: &mut R) -> Res<Self> {
+				let mut buf = [0; size_of::<$int>()];
+				reader.read_exact(&mut buf[size_of::<$int>() - $bytes..])?;
+				Ok(Self(Self::sign_extend($int::from_be_bytes(buf))))
+			}
+		}
+
+		impl<R: ByteRead> Deserialize<LittleEndian, R> for $t {
+			fn deserialize(reader: &mut R) -> Res<Self> {
+				let mut buf = [0; size_of::<$int>()];
+				reader.read_exact(&mut buf[..$bytes])?;
+				Ok(Self(Self::sign_extend($int::from_le_bytes(buf))))
+			}
+		}
+
+		impl<W: ByteWrite> Serialize<BigEndian, W> for $t {
+			fn serialize(self, writer: &mut W) -> Res<()> {
+				let buf = self.to_bytes_checked($int::to_be_bytes)?;
+				writer.write_all(&buf[size_of::<$int>() - $bytes..])
+			}
+		}
+
+		impl<W: ByteWrite> Serialize<LittleEndian, W> for $t {
+			fn serialize(self, writer: &mut W) -> Res<()> {
+				let buf = self.to_bytes_checked($int::to_le_bytes)?;
+				writer.write_all(&buf[..$bytes])
+			}
+		}
+
+		impl<E: Endianness, W> Serialize<E, W> for &$t where $t: Serialize<E, W> {
+			fn serialize(self, writer: &mut W) -> Res<()> {
+				(*self).serialize(writer)
+			}
+		}
+	}
+}
+
+impl_odd_int!(U24, u32, 3, 0, (1 << 24) - 1, "An unsigned 24-bit (3-byte) integer.");
+impl_odd_int!(I24, i32, 3, -(1 << 23), (1 << 23) - 1, "A signed 24-bit (3-byte) integer.");
+impl_odd_int!(U40, u64, 5, 0, (1 << 40) - 1, "An unsigned 40-bit (5-byte) integer.");
+impl_odd_int!(I40, i64, 5, -(1 << 39), (1 << 39) - 1, "A signed 40-bit (5-byte) integer.");
+impl_odd_int!(U48, u64, 6, 0, (1 << 48) - 1, "An unsigned 48-bit (6-byte) integer.");
+impl_odd_int!(I48, i64, 6, -(1 << 47), (1 << 47) - 1, "A signed 48-bit (6-byte) integer.");
+impl_odd_int!(U56, u64, 7, 0, (1 << 56) - 1, "An unsigned 56-bit (7-byte) integer.");
+impl_odd_int!(I56, i64, 7, -(1 << 55), (1 << 55) - 1, "A signed 56-bit (7-byte) integer.");
+
+#[cfg(test)]
+mod tests {
+	use crate::io;
+	use crate::{I24, I48, U24, U40, U56};
+
+	#[test]
+	fn read_u24() {
+		let data = b"\xba\xad\xf0";
+		let mut val: U24;
+		{
+			use crate::BERead;
+			let mut reader = &data[..];
+			val = reader.read().unwrap();
+			assert_eq!(val, U24(0xbaadf0));
+		}
+		{
+			use crate::LERead;
+			let mut reader = &data[..];
+			val = reader.read().unwrap();
+			assert_eq!(val, U24(0xf0adba));
+		}
+	}
+
+	#[test]
+	fn read_i24() {
+		let data = b"\xff\xff\xfe";
+		let mut val: I24;
+		{
+			use crate::BERead;
+			let mut reader = &data[..];
+			val = reader.read().unwrap();
+			assert_eq!(val, I24(-2));
+		}
+		{
+			use crate::LERead;
+			let mut reader = &data[..];
+			val = reader.read().unwrap();
+			assert_eq!(val, I24(-0x010001));
+		}
+	}
+
+	#[test]
+	fn read_i48_positive() {
+		let data = b"\x7f\xff\xff\xff\xff\xff";
+		use crate::BERead;
+		let mut reader = &data[..];
+		let val: I48 = reader.read().unwrap();
+		assert_eq!(val.0, I48::MAX);
+	}
+
+	#[test]
+	fn read_u56() {
+		let data = b"\x01\x02\x03\x04\x05\x06\x07";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let val: U56 = reader.read().unwrap();
+		assert_eq!(val.0, 0x07060504030201);
+	}
+
+	#[test]
+	fn write_u40() {
+		let data = b"\xba\xad\xf0\x0d\x2a";
+		{
+			use crate::BEWrite;
+			let mut writer = vec![];
+			writer.write(U40(0xbaadf00d2a)).unwrap();
+			assert_eq!(writer, data);
+		}
+		{
+			use crate::LEWrite;
+			let mut writer = vec![];
+			writer.write(U40(0x2a0df0adba)).unwrap();
+			assert_eq!(writer, data);
+		}
+	}
+
+	#[test]
+	fn write_i24() {
+		use crate::BEWrite;
+		let mut writer = vec![];
+		writer.write(I24(I24::MIN)).unwrap();
+		writer.write(I24(-1)).unwrap();
+		assert_eq!(writer, b"\x80\x00\x00\xff\xff\xff");
+	}
+
+	#[test]
+	fn write_out_of_range() {
+		use crate::LEWrite;
+		let mut writer = vec![];
+		assert_eq!(writer.write(U24(1 << 24)).unwrap_err().kind(), io::ErrorKind::InvalidInput);
+		assert_eq!(writer.write(I24(1 << 23)).unwrap_err().kind(), io::ErrorKind::InvalidInput);
+		assert_eq!(writer.write(I24(-(1 << 23) - 1)).unwrap_err().kind(), io::ErrorKind::InvalidInput);
+		assert!(writer.is_empty());
+	}
+}
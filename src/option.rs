@@ -0,0 +1,273 @@
+use core::convert::TryFrom;
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut, Not};
+
+use crate::io;
+use crate::io::Result as Res;
+use crate::{Deserialize, Endianness, ERead, EWrite, LenPrefixed, Serialize};
+use crate::len_prefixed::{read_len, write_len};
+
+/**
+	An `Option<T>` where `None` is represented by a sentinel value of `T`.
+
+	Plain `Option`s are (de-)serialized with a bool tag. This wrapper instead reads a `T`, and returns `None` if it is the sentinel value decided by `S`, or `Some` otherwise. Writing `None` writes the sentinel value.
+
+	Writing `Some` with a value equal to the sentinel returns an `InvalidInput` error, since it would be read back as `None`.
+
+	## Examples
+
+	```
+	use endio::{AllOnesSentinel, LERead, LEWrite, SentinelOption};
+
+	type Index = SentinelOption<u32, AllOnesSentinel>;
+
+	let mut reader = &b"\xff\xff\xff\xff\x2a\x00\x00\x00"[..];
+	let val: Index = reader.read().unwrap();
+	assert_eq!(*val, None);
+	let val: Index = reader.read().unwrap();
+	assert_eq!(*val, Some(42));
+
+	let mut writer = vec![];
+	writer.write(&Index::new(None)).unwrap();
+	assert_eq!(writer, b"\xff\xff\xff\xff");
+	assert!(writer.write(&Index::new(Some(u32::MAX))).is_err());
+	```
+*/
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SentinelOption<T, S> {
+	inner: Option<T>,
+	sentinel: PhantomData<S>,
+}
+
+/**
+	Decides which value of `T` represents `None` in a [`SentinelOption`].
+
+	Use one of the provided sentinels [`ZeroSentinel`], [`AllOnesSentinel`] or [`ConstSentinel`], or implement this for your own types.
+*/
+pub trait Sentinel<T> {
+	/// Returns the sentinel value, or an `InvalidInput` error if it can't be represented in `T`.
+	fn sentinel() -> Res<T>;
+
+	/// Returns whether the value is the sentinel value.
+	fn is_sentinel(value: &T) -> bool;
+}
+
+/// Uses the default value of `T` as the sentinel, which is zero for integers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ZeroSentinel;
+
+/// Uses the integer with all bits set as the sentinel, like `0xffffffff` for `u32` and `-1` for `i32`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AllOnesSentinel;
+
+/// Uses the integer `V` as the sentinel.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ConstSentinel<const V: u128>;
+
+impl<T: Default + PartialEq> Sentinel<T> for ZeroSentinel {
+	fn sentinel() -> Res<T> {
+		Ok(T::default())
+	}
+
+	fn is_sentinel(value: &T) -> bool {
+		*value == T::default()
+	}
+}
+
+impl<T: Default + Not<Output=T> + PartialEq> Sentinel<T> for AllOnesSentinel {
+	fn sentinel() -> Res<T> {
+		Ok(!T::default())
+	}
+
+	fn is_sentinel(value: &T) -> bool {
+		*value == !T::default()
+	}
+}
+
+impl<T: TryFrom<u128> + PartialEq, const V: u128> Sentinel<T> for ConstSentinel<V> {
+	fn sentinel() -> Res<T> {
+		T::try_from(V).map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "sentinel value does not fit in value type"))
+	}
+
+	fn is_sentinel(value: &T) -> bool {
+		T::try_from(V).is_ok_and(|x| *value == x)
+	}
+}
+
+impl<T, S> SentinelOption<T, S> {
+	/// Wraps the option.
+	pub fn new(inner: Option<T>) -> Self {
+		Self { inner, sentinel: PhantomData }
+	}
+
+	/// Unwraps the option.
+	pub fn into_inner(self) -> Option<T> {
+		self.inner
+	}
+}
+
+impl<T, S> From<Option<T>> for SentinelOption<T, S> {
+	fn from(inner: Option<T>) -> Self {
+		Self::new(inner)
+	}
+}
+
+impl<T, S> Deref for SentinelOption<T, S> {
+	type Target = Option<T>;
+
+	fn deref(&self) -> &Option<T> {
+		&self.inner
+	}
+}
+
+impl<T, S> DerefMut for SentinelOption<T, S> {
+	fn deref_mut(&mut self) -> &mut Option<T> {
+		&mut self.inner
+	}
+}
+
+/// Reads a `T`, which is `None` if it is the sentinel value.
+impl<E: Endianness, R: ERead<E>, T, S> Deserialize<E, R> for SentinelOption<T, S>
+	where T: Deserialize<E, R>,
+	      S: Sentinel<T> {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let value = reader.read()?;
+		if S::is_sentinel(&value) {
+			Ok(Self::new(None))
+		} else {
+			Ok(Self::new(Some(value)))
+		}
+	}
+}
+
+/// Writes the value, or the sentinel value if it is `None`.
+impl<E: Endianness, W: EWrite<E>, T, S> Serialize<E, W> for &SentinelOption<T, S>
+	where for<'a> &'a T: Serialize<E, W>,
+	      S: Sentinel<T> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		match &self.inner {
+			Some(x) if S::is_sentinel(x) => Err(io::Error::new(io::ErrorKind::InvalidInput, "Some value is equal to the sentinel value")),
+			Some(x) => writer.write(x),
+			None => writer.write(&S::sentinel()?),
+		}
+	}
+}
+
+/**
+	An `Option<T>` preceded by its number of elements, 0 or 1.
+
+	Like other [`LenPrefixed`] collections, the count is (de-)serialized as `L` in the stream's endianness. Reading returns an `InvalidData` error if the count is neither 0 nor 1.
+
+	## Examples
+
+	```
+	use endio::{BERead, BEWrite, LenOption};
+
+	let mut reader = &b"\x00\x01\xba\xad"[..];
+	let val: LenOption<u16, u16> = reader.read().unwrap();
+	assert_eq!(*val, Some(0xbaad));
+
+	let mut writer = vec![];
+	writer.write(&LenOption::<u16, u16>::new(None)).unwrap();
+	assert_eq!(writer, b"\x00\x00");
+	```
+*/
+pub type LenOption<L, T> = LenPrefixed<L, Option<T>>;
+
+/// Reads a count of type `L`, then reads `T` if it is 1.
+impl<E: Endianness, R: ERead<E>, L, T> Deserialize<E, R> for LenPrefixed<L, Option<T>>
+	where L: Deserialize<E, R>,
+	      usize: TryFrom<L>,
+	      T: Deserialize<E, R> {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		match read_len::<E, R, L>(reader)? {
+			0 => Ok(Self::new(None)),
+			1 => Ok(Self::new(Some(reader.read()?))),
+			_ => Err(io::Error::new(io::ErrorKind::InvalidData, "LenOption had count other than 0 or 1")),
+		}
+	}
+}
+
+/// Writes a count of 0 or 1 as `L`, then writes `T` if it is `Some`.
+impl<E: Endianness, W: EWrite<E>, L, S> Serialize<E, W> for &LenPrefixed<L, Option<S>>
+	where L: Serialize<E, W> + TryFrom<usize>,
+	      for<'a> &'a S: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		write_len::<E, W, L>(writer, self.iter().len())?;
+		match &**self {
+			Some(x) => writer.write(x),
+			None => Ok(()),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::io;
+	use crate::{AllOnesSentinel, ConstSentinel, LenOption, SentinelOption, ZeroSentinel};
+
+	#[test]
+	fn read_sentinel_option() {
+		let data = b"\x00\x00\x2a\x00";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let val: SentinelOption<u16, ZeroSentinel> = reader.read().unwrap();
+		assert_eq!(*val, None);
+		let val: SentinelOption<u16, ZeroSentinel> = reader.read().unwrap();
+		assert_eq!(*val, Some(42));
+	}
+
+	#[test]
+	fn read_sentinel_option_all_ones() {
+		let data = b"\xff\xff\xff\xfe";
+		use crate::BERead;
+		let mut reader = &data[..];
+		let val: SentinelOption<i16, AllOnesSentinel> = reader.read().unwrap();
+		assert_eq!(*val, None);
+		let val: SentinelOption<i16, AllOnesSentinel> = reader.read().unwrap();
+		assert_eq!(*val, Some(-2));
+	}
+
+	#[test]
+	fn write_sentinel_option() {
+		let data = b"\xff\x00\x2a";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(&SentinelOption::<u16, ConstSentinel<0xff>>::new(None)).unwrap();
+		writer.write(&SentinelOption::<u8, ConstSentinel<0xff>>::new(Some(42))).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn write_sentinel_option_some_sentinel() {
+		use crate::LEWrite;
+		let mut writer = vec![];
+		let err = writer.write(&SentinelOption::<u32, ZeroSentinel>::new(Some(0))).unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+		let err = writer.write(&SentinelOption::<u8, ConstSentinel<256>>::new(None)).unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+		assert!(writer.is_empty());
+	}
+
+	#[test]
+	fn read_len_option() {
+		let data = b"\x00\x01\x2a\x02\x2a";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let val: LenOption<u8, u8> = reader.read().unwrap();
+		assert_eq!(*val, None);
+		let val: LenOption<u8, u8> = reader.read().unwrap();
+		assert_eq!(*val, Some(42));
+		let err = reader.read::<LenOption<u8, u8>>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+	}
+
+	#[test]
+	fn write_len_option() {
+		let data = b"\x01\x00\x00\x00\x2a\x00";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(&LenOption::<u32, u16>::new(Some(42))).unwrap();
+		assert_eq!(writer, data);
+	}
+}
@@ -1,7 +1,5 @@
-use std::io::Read;
-use std::io::Result as Res;
-
-use crate::{BigEndian, Deserialize, Endianness, LittleEndian};
+use crate::io::Result as Res;
+use crate::{BigEndian, ByteRead, Deserialize, Endianness, LittleEndian, NativeEndian};
 
 /**
 	Only necessary for custom (de-)serializations.
@@ -65,9 +63,23 @@ pub trait LERead: Sized {
 	fn read_le<D: Deserialize<LittleEndian, Self>>(&mut self) -> Res<D> { D::deserialize(self) }
 }
 
-impl<R: Read, E: Endianness> ERead<E> for R {}
-impl<R: Read> BERead for R {}
-impl<R: Read> LERead for R {}
+/**
+	Use this to `read` in the **native** endianness of the target platform.
+
+	Wrapper for `ERead<NativeEndian>`.
+
+	This exists solely to make `use` notation work. See `ERead` for documentation. For network byte order, use `BERead`.
+*/
+pub trait NERead: Sized {
+	fn read   <D: Deserialize<NativeEndian, Self>>(&mut self) -> Res<D> { D::deserialize(self) }
+	fn read_be<D: Deserialize<BigEndian,    Self>>(&mut self) -> Res<D> { D::deserialize(self) }
+	fn read_le<D: Deserialize<LittleEndian, Self>>(&mut self) -> Res<D> { D::deserialize(self) }
+}
+
+impl<R: ByteRead, E: Endianness> ERead<E> for R {}
+impl<R: ByteRead> BERead for R {}
+impl<R: ByteRead> LERead for R {}
+impl<R: ByteRead> NERead for R {}
 
 #[cfg(test)]
 mod tests {
@@ -88,4 +100,12 @@ mod tests {
 		let val: u16 = reader.read_le().unwrap();
 		assert_eq!(val, 0xadba);
 	}
+
+	#[test]
+	fn read_native() {
+		use crate::NERead;
+		let mut reader = DATA;
+		let val: u16 = reader.read().unwrap();
+		assert_eq!(val, u16::from_ne_bytes([0xba, 0xad]));
+	}
 }
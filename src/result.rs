@@ -0,0 +1,167 @@
+use core::convert::TryFrom;
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+
+use crate::io;
+use crate::io::Result as Res;
+use crate::{Deserialize, Endianness, ERead, EWrite, Serialize};
+
+/**
+	A `Result<T, F>` with a configurable tag.
+
+	Plain `Result`s are (de-)serialized with a bool tag, followed by `T` if the tag is `true` or `F` if it is `false`. This wrapper uses a tag of type `L` instead, with the value `OK` for `Ok` and `ERR` for `Err`. The tag values need to be non-negative, and must differ from each other. This is checked at compile time:
+
+	```compile_fail
+	use endio::{LERead, TaggedResult};
+
+	let mut reader = &b"\x01\x2a"[..];
+	let val: TaggedResult<u8, u8, u8, 1, 1> = reader.read().unwrap();
+	```
+
+	Reading returns an `InvalidData` error if the tag is neither `OK` nor `ERR`. Writing returns an `InvalidInput` error if the tag value doesn't fit in `L`.
+
+	## Examples
+
+	```
+	use endio::{LERead, LEWrite, TaggedResult};
+
+	type Reply = TaggedResult<u16, u8, u32, 0, 0xff>;
+
+	let mut reader = &b"\x00\x00\x00\x00\x2a\x00\xff\x00\x00\x00\x01"[..];
+	let val: Reply = reader.read().unwrap();
+	assert_eq!(*val, Ok(42));
+	let val: Reply = reader.read().unwrap();
+	assert_eq!(*val, Err(1));
+
+	let mut writer = vec![];
+	writer.write(&Reply::new(Ok(42))).unwrap();
+	assert_eq!(writer, b"\x00\x00\x00\x00\x2a\x00");
+	```
+*/
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TaggedResult<T, F, L = u8, const OK: u128 = 1, const ERR: u128 = 0> {
+	inner: Result<T, F>,
+	tag_type: PhantomData<L>,
+}
+
+impl<T, F, L, const OK: u128, const ERR: u128> TaggedResult<T, F, L, OK, ERR> {
+	/// Fails to compile if `Ok` and `Err` would have the same tag, since `Err` could then never be read back.
+	const TAGS_DIFFER: () = assert!(OK != ERR, "OK and ERR must be different tag values");
+
+	/// Wraps the result.
+	pub fn new(inner: Result<T, F>) -> Self {
+		Self { inner, tag_type: PhantomData }
+	}
+
+	/// Unwraps the result.
+	pub fn into_inner(self) -> Result<T, F> {
+		self.inner
+	}
+}
+
+impl<T, F, L, const OK: u128, const ERR: u128> From<Result<T, F>> for TaggedResult<T, F, L, OK, ERR> {
+	fn from(inner: Result<T, F>) -> Self {
+		Self::new(inner)
+	}
+}
+
+impl<T, F, L, const OK: u128, const ERR: u128> Deref for TaggedResult<T, F, L, OK, ERR> {
+	type Target = Result<T, F>;
+
+	fn deref(&self) -> &Result<T, F> {
+		&self.inner
+	}
+}
+
+impl<T, F, L, const OK: u128, const ERR: u128> DerefMut for TaggedResult<T, F, L, OK, ERR> {
+	fn deref_mut(&mut self) -> &mut Result<T, F> {
+		&mut self.inner
+	}
+}
+
+/// Reads a tag of type `L`, then reads `T` if it is `OK` or `F` if it is `ERR`.
+impl<E: Endianness, R: ERead<E>, T, F, L, const OK: u128, const ERR: u128> Deserialize<E, R> for TaggedResult<T, F, L, OK, ERR>
+	where T: Deserialize<E, R>,
+	      F: Deserialize<E, R>,
+	      L: Deserialize<E, R> + TryFrom<u128> + PartialEq {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let () = Self::TAGS_DIFFER;
+		let tag: L = reader.read()?;
+		if L::try_from(OK).is_ok_and(|ok| tag == ok) {
+			Ok(Self::new(Ok(reader.read()?)))
+		} else if L::try_from(ERR).is_ok_and(|err| tag == err) {
+			Ok(Self::new(Err(reader.read()?)))
+		} else {
+			Err(io::Error::new(io::ErrorKind::InvalidData, "TaggedResult had tag other than the Ok or Err value"))
+		}
+	}
+}
+
+/// Writes a tag of type `L` with the value `OK` or `ERR`, then writes `T` or `F`.
+impl<E: Endianness, W: EWrite<E>, T, F, L, const OK: u128, const ERR: u128> Serialize<E, W> for &TaggedResult<T, F, L, OK, ERR>
+	where for<'a> &'a T: Serialize<E, W>,
+	      for<'a> &'a F: Serialize<E, W>,
+	      L: Serialize<E, W> + TryFrom<u128> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		let () = TaggedResult::<T, F, L, OK, ERR>::TAGS_DIFFER;
+		let tag = if self.is_ok() { OK } else { ERR };
+		let tag = L::try_from(tag).map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "tag value does not fit in tag type"))?;
+		writer.write(tag)?;
+		match &self.inner {
+			Ok(x) => writer.write(x),
+			Err(x) => writer.write(x),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::io;
+	use crate::TaggedResult;
+
+	#[test]
+	fn read_default_tag() {
+		let data = b"\x01\x2a\x00\x2b";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let val: TaggedResult<u8, u8> = reader.read().unwrap();
+		assert_eq!(*val, Ok(0x2a));
+		let val: TaggedResult<u8, u8> = reader.read().unwrap();
+		assert_eq!(*val, Err(0x2b));
+	}
+
+	#[test]
+	fn read_custom_tag() {
+		let data = b"\x00\x02\xba\xad";
+		use crate::BERead;
+		let mut reader = &data[..];
+		let val: TaggedResult<u8, u16, u16, 1, 2> = reader.read().unwrap();
+		assert_eq!(*val, Err(0xbaad));
+	}
+
+	#[test]
+	fn read_invalid_tag() {
+		let data = b"\x03\x2a";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let err = reader.read::<TaggedResult<u8, u8, u8, 1, 2>>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+	}
+
+	#[test]
+	fn write_custom_tag() {
+		let data = b"\x02\x00\xad\xba";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(&TaggedResult::<u8, u16, u16, 1, 2>::new(Err(0xbaad))).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn write_tag_too_large() {
+		use crate::LEWrite;
+		let mut writer = vec![];
+		let err = writer.write(&TaggedResult::<u8, u8, u8, 256, 0>::new(Ok(1))).unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+	}
+}
@@ -1,8 +1,22 @@
-use std::io::Result as Res;
-use std::io::Write;
-use std::net::Ipv4Addr;
-
-use crate::{BEWrite, BigEndian, Endianness, EWrite, LEWrite, LittleEndian};
+use alloc::borrow::{Cow, ToOwned};
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+use core::marker::PhantomData;
+use core::mem::{size_of, size_of_val};
+use core::num::{NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128};
+use core::num::Wrapping;
+use core::ops::{Deref, Range, RangeInclusive};
+use core::time::Duration;
+#[cfg(feature="std")]
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+#[cfg(not(feature="std"))]
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+use crate::io::Result as Res;
+use crate::{BEWrite, BigEndian, ByteWrite, Endianness, EWrite, LEWrite, LittleEndian};
 
 /**
 	Implement this for your types to be able to `write` them.
@@ -200,7 +214,7 @@ use crate::{BEWrite, BigEndian, Endianness, EWrite, LEWrite, LittleEndian};
 
 	- Rust currently can't recognize sealed traits. Even though there are only two endiannesses, and the primitive types are implemented for them, the compiler can't recognize that. If/When the compiler gets smarter about sealed traits this will be resolved. Alternatively, once Rust gets support for specialization, I will be able to add a dummy blanket `impl` to primitives which will work around this issue.
 
-	- The underlying `W` type needs to implement `std::io::Write` to be able to write primitive types. You can work around this by explicitly specifying `Write` as trait bound, but since both `Write` and `EWrite` have a `write` method, Rust will force you to use UFCS syntax to disambiguate between them. This makes using `write` less ergonomic, and I personally think that `where` clauses are the better alternative here, since they avoid this issue.
+	- The underlying `W` type needs to implement `ByteWrite` (which every `std::io::Write` does) to be able to write primitive types. You can work around this by explicitly specifying `Write` as trait bound, but since both `Write` and `EWrite` have a `write` method, Rust will force you to use UFCS syntax to disambiguate between them. This makes using `write` less ergonomic, and I personally think that `where` clauses are the better alternative here, since they avoid this issue.
 
 		Ideally I'd like to make `std::io::Write` a supertrait of `EWrite`, since the serialization will normally depend on `Write` anyway. Unfortunately, supertraits' methods automatically get brought into scope, so this would mean that you would be forced to use UFCS every time, without being able to work around them with `where` clauses. ([Rust issue #17151](https://github.com/rust-lang/rust/issues/17151)).
 	```
@@ -210,14 +224,13 @@ use crate::{BEWrite, BigEndian, Endianness, EWrite, LEWrite, LittleEndian};
 		c: u32,
 	}
 	# {
-	use std::io::Result;
-	use endio::{Endianness, EWrite, Serialize};
+	use endio::{Endianness, Error, EWrite, Serialize};
 
 	impl<E: Endianness, W: EWrite<E>> Serialize<E, W> for &Example
 		where u8  : Serialize<E, W>,
 		      bool: Serialize<E, W>,
 		      u32 : Serialize<E, W> {
-		fn serialize(self, writer: &mut W) -> Result<()> {
+		fn serialize(self, writer: &mut W) -> Result<(), Error> {
 			writer.write(self.a)?;
 			writer.write(self.b)?;
 			writer.write(self.c)
@@ -241,17 +254,16 @@ use crate::{BEWrite, BigEndian, Endianness, EWrite, LEWrite, LittleEndian};
 	# }
 	```
 
-	### Serialize a primitive / something where you need to use the bare `std::io::Write` functionality:
+	### Serialize a primitive / something where you need to use the bare `ByteWrite` functionality:
 
-	Note how the trait bound for `W` is `Write`.
+	Note how the trait bound for `W` is `ByteWrite`. With the `std` feature, you can also use `std::io::Write` and its other methods.
 	```
-	use std::io::{Result, Write};
-	use endio::{Endianness, EWrite, Serialize};
+	use endio::{ByteWrite, Endianness, Error, EWrite, Serialize};
 
 	struct new_u8(u8);
 
-	impl<E: Endianness, W: Write> Serialize<E, W> for &new_u8 {
-		fn serialize(self, writer: &mut W) -> Result<()> {
+	impl<E: Endianness, W: ByteWrite> Serialize<E, W> for &new_u8 {
+		fn serialize(self, writer: &mut W) -> Result<(), Error> {
 			let mut buf = [0; 1];
 			buf[0] = self.0;
 			writer.write_all(&buf);
@@ -264,22 +276,21 @@ use crate::{BEWrite, BigEndian, Endianness, EWrite, LEWrite, LittleEndian};
 
 	Note how instead of using a trait bound on Endianness, we implement Serialize twice, once for `BigEndian` and once for `LittleEndian`.
 	```
-	use std::io::{Result, Write};
 	use std::mem::size_of;
-	use endio::{BigEndian, Serialize, LittleEndian};
+	use endio::{BigEndian, ByteWrite, Error, Serialize, LittleEndian};
 
 	struct new_u16(u16);
 
-	impl<W: Write> Serialize<BigEndian, W> for new_u16 {
-		fn serialize(self, writer: &mut W) -> Result<()> {
+	impl<W: ByteWrite> Serialize<BigEndian, W> for new_u16 {
+		fn serialize(self, writer: &mut W) -> Result<(), Error> {
 			let mut buf = [0; size_of::<u16>()];
 			writer.write_all(&self.0.to_be_bytes())?;
 			Ok(())
 		}
 	}
 
-	impl<W: Write> Serialize<LittleEndian, W> for new_u16 {
-		fn serialize(self, writer: &mut W) -> Result<()> {
+	impl<W: ByteWrite> Serialize<LittleEndian, W> for new_u16 {
+		fn serialize(self, writer: &mut W) -> Result<(), Error> {
 			writer.write_all(&self.0.to_le_bytes())?;
 			Ok(())
 		}
@@ -289,16 +300,52 @@ use crate::{BEWrite, BigEndian, Endianness, EWrite, LEWrite, LittleEndian};
 pub trait Serialize<E: Endianness, W> {
 	/// Serializes the type by writing to the writer.
 	fn serialize(self, writer: &mut W) -> Res<()>;
+
+	/**
+		Serializes an array of `N` values by writing to the writer.
+
+		Only for internal use: This is overridden by types that can write consecutive values faster than one by one. There's no need to implement this yourself.
+	*/
+	#[doc(hidden)]
+	fn serialize_array<const N: usize>(array: [Self; N], writer: &mut W) -> Res<()> where Self: Sized {
+		for elem in IntoIterator::into_iter(array) {
+			elem.serialize(writer)?;
+		}
+		Ok(())
+	}
+
+	/**
+		Serializes a slice of the referenced type by writing to the writer.
+
+		Only for internal use: This is overridden by references to types that can write consecutive values faster than one by one. There's no need to implement this yourself.
+	*/
+	#[doc(hidden)]
+	fn serialize_slice(slice: &[<Self as Deref>::Target], writer: &mut W) -> Res<()>
+		where Self: Deref,
+		      <Self as Deref>::Target: Sized,
+		      for<'a> &'a <Self as Deref>::Target: Serialize<E, W> {
+		for elem in slice {
+			elem.serialize(writer)?;
+		}
+		Ok(())
+	}
+}
+
+/// Views a slice of primitive numbers as its bytes in native endianness.
+///
+/// Safety: `T` must not have any padding bytes, which is the case for integers and floats.
+unsafe fn as_bytes<T>(slice: &[T]) -> &[u8] {
+	core::slice::from_raw_parts(slice.as_ptr() as *const u8, size_of_val(slice))
 }
 
 macro_rules! impl_ref {
 	($t:ident) => {
-		impl<W: Write+BEWrite> Serialize<BigEndian, W> for &$t {
+		impl<W: ByteWrite+BEWrite> Serialize<BigEndian, W> for &$t {
 			fn serialize(self, writer: &mut W) -> Res<()> {
 				BEWrite::write(writer, *self)
 			}
 		}
-		impl<W: Write+LEWrite> Serialize<LittleEndian, W> for &$t {
+		impl<W: ByteWrite+LEWrite> Serialize<LittleEndian, W> for &$t {
 			fn serialize(self, writer: &mut W) -> Res<()> {
 				LEWrite::write(writer, *self)
 			}
@@ -306,25 +353,59 @@ macro_rules! impl_ref {
 	}
 }
 
+macro_rules! impl_ref_slice {
+	($t:ident, $e:ident, $ewrite:ident, $endian:literal, $to_bytes:ident) => {
+		impl<W: ByteWrite+$ewrite> Serialize<$e, W> for &$t {
+			fn serialize(self, writer: &mut W) -> Res<()> {
+				$ewrite::write(writer, *self)
+			}
+
+			fn serialize_slice(slice: &[<Self as Deref>::Target], writer: &mut W) -> Res<()> {
+				if cfg!(target_endian=$endian) || size_of::<$t>() == 1 {
+					writer.write_all(unsafe { as_bytes(slice) })
+				} else {
+					let mut buf = Vec::with_capacity(size_of_val(slice));
+					for elem in slice {
+						buf.extend_from_slice(&elem.$to_bytes());
+					}
+					writer.write_all(&buf)
+				}
+			}
+		}
+	};
+	($t:ident) => {
+		impl_ref_slice!($t, BigEndian, BEWrite, "big", to_be_bytes);
+		impl_ref_slice!($t, LittleEndian, LEWrite, "little", to_le_bytes);
+	}
+}
+
 macro_rules! impl_int {
 	($t:ident) => {
-		impl<W: Write> Serialize<BigEndian, W> for $t {
+		impl<W: ByteWrite> Serialize<BigEndian, W> for $t {
 			fn serialize(self, writer: &mut W) -> Res<()> {
 				writer.write_all(&self.to_be_bytes())
 			}
+
+			fn serialize_array<const N: usize>(array: [Self; N], writer: &mut W) -> Res<()> {
+				<&Self as Serialize<BigEndian, W>>::serialize_slice(&array, writer)
+			}
 		}
 
-		impl<W: Write> Serialize<LittleEndian, W> for $t {
+		impl<W: ByteWrite> Serialize<LittleEndian, W> for $t {
 			fn serialize(self, writer: &mut W) -> Res<()> {
 				writer.write_all(&self.to_le_bytes())
 			}
+
+			fn serialize_array<const N: usize>(array: [Self; N], writer: &mut W) -> Res<()> {
+				<&Self as Serialize<LittleEndian, W>>::serialize_slice(&array, writer)
+			}
 		}
 
-		impl_ref!($t);
+		impl_ref_slice!($t);
 
 		#[cfg(test)]
 		mod $t {
-			use std::mem::size_of;
+			use core::mem::size_of;
 
 			#[test]
 			fn test() {
@@ -363,39 +444,145 @@ impl<E: Endianness, W: EWrite<E>> Serialize<E, W> for f32 where u32: Serialize<E
 	fn serialize(self, writer: &mut W) -> Res<()> {
 		writer.write(self.to_bits())
 	}
+
+	fn serialize_array<const N: usize>(array: [Self; N], writer: &mut W) -> Res<()> {
+		writer.write(array.map(Self::to_bits))
+	}
 }
-impl_ref!(f32);
+impl_ref_slice!(f32);
 
 impl<E: Endianness, W: EWrite<E>> Serialize<E, W> for f64 where u64: Serialize<E, W> {
 	fn serialize(self, writer: &mut W) -> Res<()> {
 		writer.write(self.to_bits())
 	}
+
+	fn serialize_array<const N: usize>(array: [Self; N], writer: &mut W) -> Res<()> {
+		writer.write(array.map(Self::to_bits))
+	}
 }
-impl_ref!(f64);
+impl_ref_slice!(f64);
 
 /// Writes a bool by writing a byte.
-impl<E: Endianness, W: Write> Serialize<E, W> for bool {
+impl<E: Endianness, W: ByteWrite> Serialize<E, W> for bool {
 	fn serialize(self, writer: &mut W) -> Res<()> {
 		writer.write_all(&(self as u8).to_ne_bytes())
 	}
 }
 impl_ref!(bool);
 
-impl<E: Endianness, W: Write> Serialize<E, W> for Ipv4Addr {
+macro_rules! impl_nonzero {
+	($t:ident, $int:ident) => {
+		impl<E: Endianness, W: EWrite<E>> Serialize<E, W> for $t where $int: Serialize<E, W> {
+			fn serialize(self, writer: &mut W) -> Res<()> {
+				writer.write(self.get())
+			}
+		}
+		impl_ref!($t);
+	}
+}
+
+impl_nonzero!(NonZeroU8, u8);
+impl_nonzero!(NonZeroU16, u16);
+impl_nonzero!(NonZeroU32, u32);
+impl_nonzero!(NonZeroU64, u64);
+impl_nonzero!(NonZeroU128, u128);
+impl_nonzero!(NonZeroI8, i8);
+impl_nonzero!(NonZeroI16, i16);
+impl_nonzero!(NonZeroI32, i32);
+impl_nonzero!(NonZeroI64, i64);
+impl_nonzero!(NonZeroI128, i128);
+
+/// Writes a char by writing it as a `u32`.
+impl<E: Endianness, W: EWrite<E>> Serialize<E, W> for char where u32: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		writer.write(self as u32)
+	}
+}
+impl_ref!(char);
+
+impl<E: Endianness, W: ByteWrite> Serialize<E, W> for Ipv4Addr {
 	fn serialize(self, writer: &mut W) -> Res<()>	{
 		writer.write_all(&self.octets()[..])
 	}
 }
 impl_ref!(Ipv4Addr);
 
-// todo[specialization]: specialize for &[u8] (std::io::Write::write_all)
-/// Writes the entire contents of the byte slice.
-impl<E: Endianness, W: EWrite<E>, S> Serialize<E, W> for &[S] where for<'a> &'a S: Serialize<E, W> {
+impl<E: Endianness, W: ByteWrite> Serialize<E, W> for Ipv6Addr {
+	fn serialize(self, writer: &mut W) -> Res<()>	{
+		writer.write_all(&self.octets()[..])
+	}
+}
+impl_ref!(Ipv6Addr);
+
+/// Writes an `IpAddr` by writing a `u8` tag of 4 or 6, followed by the address.
+impl<E: Endianness, W: EWrite<E>> Serialize<E, W> for IpAddr
+	where u8: Serialize<E, W>,
+	      Ipv4Addr: Serialize<E, W>,
+	      Ipv6Addr: Serialize<E, W> {
 	fn serialize(self, writer: &mut W) -> Res<()> {
-		for elem in self {
-			writer.write(elem)?;
+		match self {
+			IpAddr::V4(ip) => {
+				writer.write(4u8)?;
+				writer.write(ip)
+			}
+			IpAddr::V6(ip) => {
+				writer.write(6u8)?;
+				writer.write(ip)
+			}
 		}
-		Ok(())
+	}
+}
+impl_ref!(IpAddr);
+
+/// Writes a `SocketAddrV4` by writing the address, followed by the port.
+impl<E: Endianness, W: EWrite<E>> Serialize<E, W> for SocketAddrV4
+	where u16: Serialize<E, W>,
+	      Ipv4Addr: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		writer.write(*self.ip())?;
+		writer.write(self.port())
+	}
+}
+impl_ref!(SocketAddrV4);
+
+/// Writes a `SocketAddrV6` by writing the address, followed by the port, flowinfo and scope id.
+impl<E: Endianness, W: EWrite<E>> Serialize<E, W> for SocketAddrV6
+	where u16: Serialize<E, W>,
+	      u32: Serialize<E, W>,
+	      Ipv6Addr: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		writer.write(*self.ip())?;
+		writer.write(self.port())?;
+		writer.write(self.flowinfo())?;
+		writer.write(self.scope_id())
+	}
+}
+impl_ref!(SocketAddrV6);
+
+/// Writes a `SocketAddr` by writing a `u8` tag of 4 or 6, followed by the socket address.
+impl<E: Endianness, W: EWrite<E>> Serialize<E, W> for SocketAddr
+	where u8: Serialize<E, W>,
+	      SocketAddrV4: Serialize<E, W>,
+	      SocketAddrV6: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		match self {
+			SocketAddr::V4(addr) => {
+				writer.write(4u8)?;
+				writer.write(addr)
+			}
+			SocketAddr::V6(addr) => {
+				writer.write(6u8)?;
+				writer.write(addr)
+			}
+		}
+	}
+}
+impl_ref!(SocketAddr);
+
+/// Writes the elements of the slice in order. Slices of integers and floats are written with a single `write_all` call.
+impl<E: Endianness, W: EWrite<E>, S> Serialize<E, W> for &[S] where for<'a> &'a S: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		<&S>::serialize_slice(self, writer)
 	}
 }
 
@@ -406,6 +593,53 @@ impl<E: Endianness, W: EWrite<E>, S> Serialize<E, W> for &Vec<S> where for<'a> &
 	}
 }
 
+/// Writes the elements of the array in order. Arrays of integers and floats are written with a single `write_all` call.
+impl<E: Endianness, W: EWrite<E>, S: Serialize<E, W>, const N: usize> Serialize<E, W> for [S; N] {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		S::serialize_array(self, writer)
+	}
+}
+
+/// Writes the elements of the array in order.
+impl<E: Endianness, W: EWrite<E>, S, const N: usize> Serialize<E, W> for &[S; N] where for<'a> &'a S: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		writer.write(&self[..])
+	}
+}
+
+macro_rules! impl_tuple {
+	($($t:ident $i:tt)+) => {
+		/// Writes the elements of the tuple in order.
+		impl<E: Endianness, W: EWrite<E>, $($t: Serialize<E, W>),+> Serialize<E, W> for ($($t,)+) {
+			fn serialize(self, writer: &mut W) -> Res<()> {
+				$(writer.write(self.$i)?;)+
+				Ok(())
+			}
+		}
+
+		/// Writes the elements of the tuple in order.
+		impl<'a, E: Endianness, W: EWrite<E>, $($t),+> Serialize<E, W> for &'a ($($t,)+) where $(&'a $t: Serialize<E, W>),+ {
+			fn serialize(self, writer: &mut W) -> Res<()> {
+				$(writer.write(&self.$i)?;)+
+				Ok(())
+			}
+		}
+	}
+}
+
+impl_tuple!(A 0);
+impl_tuple!(A 0 B 1);
+impl_tuple!(A 0 B 1 C 2);
+impl_tuple!(A 0 B 1 C 2 D 3);
+impl_tuple!(A 0 B 1 C 2 D 3 F 4);
+impl_tuple!(A 0 B 1 C 2 D 3 F 4 G 5);
+impl_tuple!(A 0 B 1 C 2 D 3 F 4 G 5 H 6);
+impl_tuple!(A 0 B 1 C 2 D 3 F 4 G 5 H 6 I 7);
+impl_tuple!(A 0 B 1 C 2 D 3 F 4 G 5 H 6 I 7 J 8);
+impl_tuple!(A 0 B 1 C 2 D 3 F 4 G 5 H 6 I 7 J 8 K 9);
+impl_tuple!(A 0 B 1 C 2 D 3 F 4 G 5 H 6 I 7 J 8 K 9 L 10);
+impl_tuple!(A 0 B 1 C 2 D 3 F 4 G 5 H 6 I 7 J 8 K 9 L 10 M 11);
+
 /// Writes an `Option<T>` by writing a bool whether the `Option` is `Some`, and if yes, writes `T`.
 impl<E: Endianness, W: EWrite<E>, S> Serialize<E, W> for & Option<S>
 	where bool: Serialize<E, W>,
@@ -419,9 +653,149 @@ impl<E: Endianness, W: EWrite<E>, S> Serialize<E, W> for & Option<S>
 	}
 }
 
+/// Writes a `Result<T, F>` by writing a bool whether the `Result` is `Ok`, then writes `T` or `F`.
+impl<E: Endianness, W: EWrite<E>, T, F> Serialize<E, W> for &Result<T, F>
+	where bool: Serialize<E, W>,
+		for<'a> &'a T: Serialize<E, W>,
+		for<'a> &'a F: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		writer.write(self.is_ok())?;
+		match self {
+			Ok(x) => writer.write(x),
+			Err(x) => writer.write(x),
+		}
+	}
+}
+
+/// Writes nothing.
+impl<E: Endianness, W> Serialize<E, W> for () {
+	fn serialize(self, _writer: &mut W) -> Res<()> {
+		Ok(())
+	}
+}
+
+/// Writes nothing.
+impl<E: Endianness, W> Serialize<E, W> for &() {
+	fn serialize(self, _writer: &mut W) -> Res<()> {
+		Ok(())
+	}
+}
+
+/// Writes nothing.
+impl<E: Endianness, W, T> Serialize<E, W> for PhantomData<T> {
+	fn serialize(self, _writer: &mut W) -> Res<()> {
+		Ok(())
+	}
+}
+
+/// Writes nothing.
+impl<E: Endianness, W, T> Serialize<E, W> for &PhantomData<T> {
+	fn serialize(self, _writer: &mut W) -> Res<()> {
+		Ok(())
+	}
+}
+
+/// Writes the referenced value.
+impl<'a, E: Endianness, W, T: ?Sized> Serialize<E, W> for &&'a T where &'a T: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		(*self).serialize(writer)
+	}
+}
+
+macro_rules! impl_pointer {
+	($t:ident) => {
+		/// Writes the pointed-to value.
+		impl<E: Endianness, W, T: ?Sized> Serialize<E, W> for &$t<T> where for<'a> &'a T: Serialize<E, W> {
+			fn serialize(self, writer: &mut W) -> Res<()> {
+				(**self).serialize(writer)
+			}
+		}
+	}
+}
+
+impl_pointer!(Box);
+impl_pointer!(Rc);
+impl_pointer!(Arc);
+
+/// Writes the borrowed or owned value.
+impl<E: Endianness, W, T: ToOwned + ?Sized> Serialize<E, W> for &Cow<'_, T> where for<'a> &'a T: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		(**self).serialize(writer)
+	}
+}
+
+macro_rules! impl_wrapper {
+	($t:ident) => {
+		/// Writes the wrapped value.
+		impl<E: Endianness, W, T: Serialize<E, W>> Serialize<E, W> for $t<T> {
+			fn serialize(self, writer: &mut W) -> Res<()> {
+				self.0.serialize(writer)
+			}
+		}
+
+		/// Writes the wrapped value.
+		impl<E: Endianness, W, T> Serialize<E, W> for &$t<T> where for<'a> &'a T: Serialize<E, W> {
+			fn serialize(self, writer: &mut W) -> Res<()> {
+				(&self.0).serialize(writer)
+			}
+		}
+	}
+}
+
+impl_wrapper!(Wrapping);
+impl_wrapper!(Reverse);
+
+/// Writes a `Range<T>` by writing the start, followed by the end.
+impl<E: Endianness, W: EWrite<E>, T: Serialize<E, W>> Serialize<E, W> for Range<T> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		writer.write(self.start)?;
+		writer.write(self.end)
+	}
+}
+
+/// Writes a `Range<T>` by writing the start, followed by the end.
+impl<E: Endianness, W: EWrite<E>, T> Serialize<E, W> for &Range<T> where for<'a> &'a T: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		writer.write(&self.start)?;
+		writer.write(&self.end)
+	}
+}
+
+/// Writes a `RangeInclusive<T>` by writing the start, followed by the end.
+impl<E: Endianness, W: EWrite<E>, T: Serialize<E, W>> Serialize<E, W> for RangeInclusive<T> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		let (start, end) = self.into_inner();
+		writer.write(start)?;
+		writer.write(end)
+	}
+}
+
+/// Writes a `RangeInclusive<T>` by writing the start, followed by the end.
+impl<E: Endianness, W: EWrite<E>, T> Serialize<E, W> for &RangeInclusive<T> where for<'a> &'a T: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		writer.write(self.start())?;
+		writer.write(self.end())
+	}
+}
+
+/// Writes a `Duration` by writing the seconds as a `u64`, followed by the nanoseconds as a `u32`.
+impl<E: Endianness, W: EWrite<E>> Serialize<E, W> for Duration where u64: Serialize<E, W>, u32: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		writer.write(self.as_secs())?;
+		writer.write(self.subsec_nanos())
+	}
+}
+
+/// Writes a `Duration` by writing the seconds as a `u64`, followed by the nanoseconds as a `u32`.
+impl<E: Endianness, W: EWrite<E>> Serialize<E, W> for &Duration where u64: Serialize<E, W>, u32: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		writer.write(*self)
+	}
+}
+
 #[cfg(test)]
 mod tests {
-	use std::io::Result as Res;
+	use crate::io::Result as Res;
 
 	#[test]
 	fn write_bool_false() {
@@ -529,6 +903,41 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn write_nonzero() {
+		use std::num::NonZeroU32;
+
+		let data = b"\xba\xad\xf0\x0d";
+		{
+			use crate::BEWrite;
+			let mut writer = vec![];
+			writer.write(NonZeroU32::new(0xbaadf00d).unwrap()).unwrap();
+			assert_eq!(writer, data);
+		}
+		{
+			use crate::LEWrite;
+			let mut writer = vec![];
+			writer.write(NonZeroU32::new(0x0df0adba).unwrap()).unwrap();
+			assert_eq!(writer, data);
+		}
+	}
+
+	#[test]
+	fn write_char() {
+		{
+			use crate::BEWrite;
+			let mut writer = vec![];
+			writer.write('\u{1f600}').unwrap();
+			assert_eq!(writer, b"\x00\x01\xf6\x00");
+		}
+		{
+			use crate::LEWrite;
+			let mut writer = vec![];
+			writer.write('\u{1f600}').unwrap();
+			assert_eq!(writer, b"\x00\xf6\x01\x00");
+		}
+	}
+
 	#[test]
 	fn write_ipv4_addr() {
 		use std::net::Ipv4Addr;
@@ -548,6 +957,78 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn write_ipv6_addr() {
+		use std::net::Ipv6Addr;
+
+		let data = b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01";
+		{
+			use crate::BEWrite;
+			let mut writer = vec![];
+			writer.write(Ipv6Addr::LOCALHOST).unwrap();
+			assert_eq!(writer, data);
+		}
+		{
+			use crate::LEWrite;
+			let mut writer = vec![];
+			writer.write(Ipv6Addr::LOCALHOST).unwrap();
+			assert_eq!(writer, data);
+		}
+	}
+
+	#[test]
+	fn write_ip_addr() {
+		use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+		let data = b"\x04\x7f\x00\x00\x01\x06\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(IpAddr::V4(Ipv4Addr::LOCALHOST)).unwrap();
+		writer.write(IpAddr::V6(Ipv6Addr::LOCALHOST)).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn write_socket_addr_v4() {
+		use std::net::{Ipv4Addr, SocketAddrV4};
+
+		let data = b"\x7f\x00\x00\x01\x1f\x90";
+		{
+			use crate::BEWrite;
+			let mut writer = vec![];
+			writer.write(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0x1f90)).unwrap();
+			assert_eq!(writer, data);
+		}
+		{
+			use crate::LEWrite;
+			let mut writer = vec![];
+			writer.write(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0x901f)).unwrap();
+			assert_eq!(writer, data);
+		}
+	}
+
+	#[test]
+	fn write_socket_addr_v6() {
+		use std::net::{Ipv6Addr, SocketAddrV6};
+
+		let data = b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01\x90\x1f\x01\x00\x00\x00\x02\x00\x00\x00";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(SocketAddrV6::new(Ipv6Addr::LOCALHOST, 0x1f90, 1, 2)).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn write_socket_addr() {
+		use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+
+		let data = b"\x04\x7f\x00\x00\x01\x1f\x90";
+		use crate::BEWrite;
+		let mut writer = vec![];
+		writer.write(SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0x1f90))).unwrap();
+		assert_eq!(writer, data);
+	}
+
 	#[test]
 	fn write_slice() {
 		let data = b"\xba\xad\xba\xad";
@@ -566,6 +1047,159 @@ mod tests {
 		assert_eq!(writer, data);
 	}
 
+	#[test]
+	fn write_slice_be() {
+		let data = b"\xba\xad\xf0\x0d\x00\x00\x00\x2a";
+		use crate::BEWrite;
+		let mut writer = vec![];
+		writer.write(&[0xbaadf00du32, 42][..]).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn write_slice_float() {
+		let data = b"\x00\x00\x80\x3f\x00\x00\x00\xc0";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(&[1.0f32, -2.0][..]).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	#[cfg(feature="std")]
+	fn write_slice_single_call() {
+		struct CountingWriter(Vec<u8>, usize);
+
+		impl std::io::Write for CountingWriter {
+			fn write(&mut self, buf: &[u8]) -> Res<usize> {
+				self.1 += 1;
+				std::io::Write::write(&mut self.0, buf)
+			}
+
+			fn flush(&mut self) -> Res<()> {
+				Ok(())
+			}
+		}
+
+		let data: Vec<i16> = (0..1000).collect();
+		{
+			use crate::BEWrite;
+			let mut writer = CountingWriter(vec![], 0);
+			writer.write(&data[..]).unwrap();
+			assert_eq!(writer.0.len(), 2000);
+			assert_eq!(&writer.0[2..4], b"\x00\x01");
+			assert_eq!(writer.1, 1);
+		}
+		{
+			use crate::LEWrite;
+			let mut writer = CountingWriter(vec![], 0);
+			writer.write(&data[..]).unwrap();
+			assert_eq!(&writer.0[2..4], b"\x01\x00");
+			assert_eq!(writer.1, 1);
+		}
+	}
+
+	#[test]
+	#[cfg(feature="std")]
+	fn write_array_single_call() {
+		struct CountingWriter(Vec<u8>, usize);
+
+		impl std::io::Write for CountingWriter {
+			fn write(&mut self, buf: &[u8]) -> Res<usize> {
+				self.1 += 1;
+				std::io::Write::write(&mut self.0, buf)
+			}
+
+			fn flush(&mut self) -> Res<()> {
+				Ok(())
+			}
+		}
+
+		{
+			use crate::BEWrite;
+			let mut writer = CountingWriter(vec![], 0);
+			writer.write([0xbaadu16, 0xf00d, 0x1234]).unwrap();
+			assert_eq!(writer.0, b"\xba\xad\xf0\x0d\x12\x34");
+			assert_eq!(writer.1, 1);
+		}
+		{
+			use crate::LEWrite;
+			let mut writer = CountingWriter(vec![], 0);
+			writer.write([1.0f32, -2.0]).unwrap();
+			assert_eq!(writer.0, b"\x00\x00\x80\x3f\x00\x00\x00\xc0");
+			assert_eq!(writer.1, 1);
+		}
+		{
+			use crate::LEWrite;
+			let mut writer = CountingWriter(vec![], 0);
+			writer.write([0x2au8; 16]).unwrap();
+			assert_eq!(writer.0, [0x2a; 16]);
+			assert_eq!(writer.1, 1);
+		}
+	}
+
+	#[test]
+	fn write_array() {
+		let data = b"\xba\xad\xf0\x0d";
+		{
+			use crate::BEWrite;
+			let mut writer = vec![];
+			writer.write([0xbaadu16, 0xf00d]).unwrap();
+			assert_eq!(writer, data);
+		}
+		{
+			use crate::LEWrite;
+			let mut writer = vec![];
+			writer.write([0xadbau16, 0x0df0]).unwrap();
+			assert_eq!(writer, data);
+		}
+	}
+
+	#[test]
+	fn write_array_ref() {
+		let data = b"\x01\x2a\x00";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(&[Some(0x2au8), None]).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn write_double_ref() {
+		let data = b"\xba\xad";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		let val: &[u8; 2] = data;
+		writer.write(&[val][..]).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn write_tuple() {
+		let data = b"\xba\xad\x01\xba\xad\xf0\x0d";
+		{
+			use crate::BEWrite;
+			let mut writer = vec![];
+			writer.write((0xbaadu16, true, 0xbaadf00du32)).unwrap();
+			assert_eq!(writer, data);
+		}
+		{
+			use crate::LEWrite;
+			let mut writer = vec![];
+			writer.write((0xadbau16, true, 0x0df0adbau32)).unwrap();
+			assert_eq!(writer, data);
+		}
+	}
+
+	#[test]
+	fn write_tuple_ref() {
+		let data = b"\x2a\x00\x01\x2a";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(&(0x2au16, Some(0x2au8))).unwrap();
+		assert_eq!(writer, data);
+	}
+
 	#[test]
 	fn write_option_none() {
 		let data = b"\x00";
@@ -585,6 +1219,74 @@ mod tests {
 		assert_eq!(writer, data);
 	}
 
+	#[test]
+	fn write_result_ok() {
+		let data = b"\x01\x42\x00";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(&Ok::<u16, u8>(0x0042)).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn write_result_err() {
+		let data = b"\x00\x42";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(&Err::<u16, u8>(0x42)).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn write_unit() {
+		use std::marker::PhantomData;
+
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(()).unwrap();
+		writer.write(PhantomData::<u32>).unwrap();
+		assert!(writer.is_empty());
+	}
+
+	#[test]
+	fn write_wrapper() {
+		use std::borrow::Cow;
+		use std::cmp::Reverse;
+		use std::num::Wrapping;
+		use std::rc::Rc;
+		use std::sync::Arc;
+
+		let data = b"\xba\xad\xf0\x0d\xba\xad\xf0\x0d\xba\xad\x01\x02";
+		use crate::BEWrite;
+		let mut writer = vec![];
+		writer.write(&Box::new(0xbaadu16)).unwrap();
+		writer.write(&Rc::new(0xf00du16)).unwrap();
+		writer.write(&Arc::new(0xbaadu16)).unwrap();
+		writer.write(Wrapping(0xf00du16)).unwrap();
+		writer.write(&Reverse(Box::new(0xbaadu16))).unwrap();
+		writer.write(&Cow::Borrowed(&b"\x01\x02"[..])).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn write_range() {
+		let data = b"\x01\x00\x02\x00\x01\x00\x02\x00";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(1u16..2).unwrap();
+		writer.write(&(Box::new(1u16)..=Box::new(2u16))).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn write_duration() {
+		let data = b"\x02\x00\x00\x00\x00\x00\x00\x00\x03\x00\x00\x00";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(std::time::Duration::new(2, 3)).unwrap();
+		assert_eq!(writer, data);
+	}
+
 	#[test]
 	fn write_struct_forced() {
 		struct Test {
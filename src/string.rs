@@ -0,0 +1,406 @@
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::ops::{Deref, DerefMut};
+
+use crate::io;
+use crate::io::Result as Res;
+use crate::{ByteRead, ByteWrite, Deserialize, Endianness, ERead, EWrite, LenPrefixed, Serialize};
+use crate::len_prefixed::{read_bytes, read_len, split_bytes, write_len};
+
+/**
+	A UTF-8 string preceded by its length in bytes.
+
+	The length is (de-)serialized as `L` in the stream's endianness, with the same rules as for other [`LenPrefixed`] collections. Reading returns an `InvalidData` error if the string is not valid UTF-8.
+
+	## Examples
+
+	```
+	use endio::{LERead, LEWrite, LenString};
+
+	let mut reader = &b"\x05\x00hello"[..];
+	let val: LenString<u16> = reader.read().unwrap();
+	assert_eq!(*val, "hello");
+
+	let mut writer = vec![];
+	writer.write(&val).unwrap();
+	assert_eq!(writer, b"\x05\x00hello");
+	```
+
+	String slices can be written directly:
+
+	```
+	use endio::{BEWrite, LenString};
+
+	let mut writer = vec![];
+	writer.write(LenString::<u8, _>::new("hello")).unwrap();
+	assert_eq!(writer, b"\x05hello");
+	```
+
+	When reading from a byte slice, `LenString<L, &str>` borrows the string from the input instead of copying it:
+
+	```
+	use endio::{LERead, LenString};
+
+	let data = b"\x05hello world";
+	let mut reader = &data[..];
+	let val: LenString<u8, &str> = reader.read().unwrap();
+	assert_eq!(*val, "hello");
+	assert_eq!(reader, b" world");
+	```
+*/
+pub type LenString<L, S = String> = LenPrefixed<L, S>;
+
+/**
+	A UTF-8 string terminated by a NUL byte, like a C string.
+
+	Reading stops at the first NUL byte, which is consumed but not included in the string. Writing appends a NUL byte, and returns an `InvalidInput` error if the string itself contains one, since it couldn't be read back correctly.
+
+	## Examples
+
+	```
+	use endio::{LERead, LEWrite, NulString};
+
+	let mut reader = &b"hello\x00world"[..];
+	let val: NulString = reader.read().unwrap();
+	assert_eq!(*val, "hello");
+	assert_eq!(reader, b"world");
+
+	let mut writer = vec![];
+	writer.write(&val).unwrap();
+	assert_eq!(writer, b"hello\x00");
+	```
+*/
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NulString(pub String);
+
+/**
+	A UTF-8 string in a fixed-width field of `N` bytes, padded with NUL bytes.
+
+	Reading always consumes `N` bytes, and the string ends at the first NUL byte, if any. Writing pads the string with NUL bytes up to `N` bytes, and returns an `InvalidInput` error if the string is longer than `N` bytes or contains a NUL byte.
+
+	## Examples
+
+	```
+	use endio::{FixedString, LERead, LEWrite};
+
+	let mut reader = &b"hello\x00\x00\x00"[..];
+	let val: FixedString<8> = reader.read().unwrap();
+	assert_eq!(*val, "hello");
+
+	let mut writer = vec![];
+	writer.write(&val).unwrap();
+	assert_eq!(writer, b"hello\x00\x00\x00");
+	```
+*/
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FixedString<const N: usize>(pub String);
+
+fn from_utf8(bytes: Vec<u8>) -> Res<String> {
+	String::from_utf8(bytes).map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "string was not valid UTF-8"))
+}
+
+fn check_no_nul(string: &str) -> Res<()> {
+	if string.as_bytes().contains(&0) {
+		return Err(io::Error::new(io::ErrorKind::InvalidInput, "string contains a NUL byte"));
+	}
+	Ok(())
+}
+
+/// Reads a length of type `L`, then reads that many bytes as UTF-8.
+impl<E: Endianness, R: ByteRead + ERead<E>, L> Deserialize<E, R> for LenPrefixed<L, String>
+	where L: Deserialize<E, R>,
+	      usize: TryFrom<L> {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let len = read_len::<E, R, L>(reader)?;
+		Ok(Self::new(from_utf8(read_bytes(reader, len)?)?))
+	}
+}
+
+/// Reads a length of type `L`, then borrows that many bytes from the input as UTF-8 without copying them.
+impl<'a, E: Endianness, L> Deserialize<E, &'a [u8]> for LenPrefixed<L, &'a str>
+	where L: Deserialize<E, &'a [u8]>,
+	      usize: TryFrom<L> {
+	fn deserialize(reader: &mut &'a [u8]) -> Res<Self> {
+		let len = read_len::<E, &'a [u8], L>(reader)?;
+		let string = core::str::from_utf8(split_bytes(reader, len)?).map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "string was not valid UTF-8"))?;
+		Ok(Self::new(string))
+	}
+}
+
+/// Writes the length of the string in bytes as `L`, then writes the string.
+impl<E: Endianness, W: ByteWrite + EWrite<E>, L> Serialize<E, W> for LenPrefixed<L, &str>
+	where L: Serialize<E, W> + TryFrom<usize> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		write_len::<E, W, L>(writer, self.len())?;
+		writer.write_all(self.as_bytes())
+	}
+}
+
+/// Writes the length of the string in bytes as `L`, then writes the string.
+impl<E: Endianness, W: ByteWrite + EWrite<E>, L> Serialize<E, W> for &LenPrefixed<L, &str>
+	where L: Serialize<E, W> + TryFrom<usize> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		EWrite::write(writer, LenString::<L, _>::new(**self))
+	}
+}
+
+/// Writes the length of the string in bytes as `L`, then writes the string.
+impl<E: Endianness, W: ByteWrite + EWrite<E>, L> Serialize<E, W> for &LenPrefixed<L, String>
+	where L: Serialize<E, W> + TryFrom<usize> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		EWrite::write(writer, LenString::<L, _>::new(self.as_str()))
+	}
+}
+
+/// Reads bytes up to and including a NUL byte.
+impl<E: Endianness, R: ByteRead> Deserialize<E, R> for NulString {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let mut bytes = vec![];
+		let mut buf = [0; 1];
+		loop {
+			reader.read_exact(&mut buf)?;
+			if buf[0] == 0 {
+				break;
+			}
+			bytes.push(buf[0]);
+		}
+		Ok(Self(from_utf8(bytes)?))
+	}
+}
+
+/// Writes the string followed by a NUL byte.
+impl<E: Endianness, W: ByteWrite> Serialize<E, W> for &NulString {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		check_no_nul(&self.0)?;
+		writer.write_all(self.0.as_bytes())?;
+		writer.write_all(&[0])
+	}
+}
+
+/// Reads `N` bytes, and uses the bytes up to the first NUL byte as the string.
+impl<E: Endianness, R: ByteRead, const N: usize> Deserialize<E, R> for FixedString<N> {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let mut buf = [0; N];
+		reader.read_exact(&mut buf)?;
+		let len = buf.iter().position(|x| *x == 0).unwrap_or(N);
+		Ok(Self(from_utf8(buf[..len].to_vec())?))
+	}
+}
+
+/// Writes the string, padded with NUL bytes to `N` bytes.
+impl<E: Endianness, W: ByteWrite, const N: usize> Serialize<E, W> for &FixedString<N> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		if self.0.len() > N {
+			return Err(io::Error::new(io::ErrorKind::InvalidInput, "string is longer than its fixed-width field"));
+		}
+		check_no_nul(&self.0)?;
+		let mut buf = [0; N];
+		buf[..self.0.len()].copy_from_slice(self.0.as_bytes());
+		writer.write_all(&buf)
+	}
+}
+
+impl From<String> for NulString {
+	fn from(string: String) -> Self {
+		Self(string)
+	}
+}
+
+impl From<&str> for NulString {
+	fn from(string: &str) -> Self {
+		Self(string.into())
+	}
+}
+
+impl Deref for NulString {
+	type Target = String;
+
+	fn deref(&self) -> &String {
+		&self.0
+	}
+}
+
+impl DerefMut for NulString {
+	fn deref_mut(&mut self) -> &mut String {
+		&mut self.0
+	}
+}
+
+impl<const N: usize> From<String> for FixedString<N> {
+	fn from(string: String) -> Self {
+		Self(string)
+	}
+}
+
+impl<const N: usize> From<&str> for FixedString<N> {
+	fn from(string: &str) -> Self {
+		Self(string.into())
+	}
+}
+
+impl<const N: usize> Deref for FixedString<N> {
+	type Target = String;
+
+	fn deref(&self) -> &String {
+		&self.0
+	}
+}
+
+impl<const N: usize> DerefMut for FixedString<N> {
+	fn deref_mut(&mut self) -> &mut String {
+		&mut self.0
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::io;
+	use crate::{FixedString, LenString, NulString};
+
+	#[test]
+	fn read_len_string() {
+		let data = b"\x00\x00\x00\x05hello";
+		use crate::BERead;
+		let mut reader = &data[..];
+		let val: LenString<u32> = reader.read().unwrap();
+		assert_eq!(*val, "hello");
+	}
+
+	#[test]
+	fn read_len_string_invalid_utf8() {
+		let data = b"\x02\xc3\x28";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let err = reader.read::<LenString<u8>>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+	}
+
+	#[test]
+	fn read_len_string_eof() {
+		let data = b"\xff\xff\xff\x7fhello";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let err = reader.read::<LenString<u32>>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+	}
+
+	#[test]
+	fn read_len_str_borrowed() {
+		let data = b"\x00\x05hello";
+		use crate::BERead;
+		let mut reader = &data[..];
+		let val: LenString<u16, &str> = reader.read().unwrap();
+		assert_eq!(*val, "hello");
+		assert!(std::ptr::eq(val.as_ptr(), data[2..].as_ptr()));
+	}
+
+	#[test]
+	fn read_len_str_borrowed_invalid_utf8() {
+		let data = b"\x02\xc3\x28";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let err = reader.read::<LenString<u8, &str>>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+	}
+
+	#[test]
+	fn write_len_string() {
+		let data = b"\x05\x00hello";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(&LenString::<u16>::new("hello".into())).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn write_len_string_too_long() {
+		use crate::LEWrite;
+		let mut writer = vec![];
+		let err = writer.write(LenString::<u8, _>::new(&*"a".repeat(256))).unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+	}
+
+	#[test]
+	fn read_nul_string() {
+		let data = b"h\xc3\xa9llo\x00rest";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let val: NulString = reader.read().unwrap();
+		assert_eq!(*val, "héllo");
+		assert_eq!(reader, b"rest");
+	}
+
+	#[test]
+	fn read_nul_string_unterminated() {
+		let data = b"hello";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let err = reader.read::<NulString>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+	}
+
+	#[test]
+	fn write_nul_string() {
+		let data = b"hello\x00";
+		use crate::BEWrite;
+		let mut writer = vec![];
+		writer.write(&NulString::from("hello")).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn write_nul_string_interior_nul() {
+		use crate::BEWrite;
+		let mut writer = vec![];
+		let err = writer.write(&NulString::from("hel\0lo")).unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+	}
+
+	#[test]
+	fn read_fixed_string() {
+		let data = b"hi\x00\x00rest";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let val: FixedString<4> = reader.read().unwrap();
+		assert_eq!(*val, "hi");
+		assert_eq!(reader, b"rest");
+	}
+
+	#[test]
+	fn read_fixed_string_full() {
+		let data = b"hello";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let val: FixedString<5> = reader.read().unwrap();
+		assert_eq!(*val, "hello");
+	}
+
+	#[test]
+	fn read_fixed_string_invalid_utf8() {
+		let data = b"\xff\x00";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let err = reader.read::<FixedString<2>>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+	}
+
+	#[test]
+	fn write_fixed_string() {
+		let data = b"hi\x00\x00";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(&FixedString::<4>::from("hi")).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn write_fixed_string_too_long() {
+		use crate::LEWrite;
+		let mut writer = vec![];
+		let err = writer.write(&FixedString::<4>::from("hello")).unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+		assert!(writer.is_empty());
+	}
+}
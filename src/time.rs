@@ -0,0 +1,516 @@
+use core::convert::TryFrom;
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+use std::time::{Duration, SystemTime};
+
+use crate::io;
+use crate::io::Result as Res;
+use crate::{Deserialize, Endianness, ERead, EWrite, Serialize};
+
+const NANOS_PER_SEC: i128 = 1_000_000_000;
+/// Seconds from 1601-01-01 to 1970-01-01.
+const FILETIME_EPOCH: i128 = 11_644_473_600;
+/// Seconds from 1900-01-01 to 1970-01-01.
+const NTP_EPOCH: i128 = 2_208_988_800;
+/// Seconds from 1904-01-01 to 1970-01-01.
+const HFS_EPOCH: i128 = 2_082_844_800;
+
+/**
+	A point in time, stored as the number of seconds since the Unix epoch (1970-01-01 00:00:00 UTC) as an integer `T`.
+
+	`T` can be `i32`, `u32`, `i64` or `u64`, and is (de-)serialized in the stream's endianness. Writing rounds down to whole seconds, and returns an `InvalidInput` error if the time doesn't fit in `T`. Reading returns an `InvalidData` error if the time can't be represented as a `SystemTime` on this platform.
+
+	## Examples
+
+	```
+	use std::time::{Duration, SystemTime};
+	use endio::{LERead, LEWrite, UnixTime};
+
+	let mut reader = &b"\x00\xf1\x53\x65"[..];
+	let val: UnixTime<u32> = reader.read().unwrap();
+	assert_eq!(*val, SystemTime::UNIX_EPOCH + Duration::from_secs(1700000000));
+
+	let mut writer = vec![];
+	writer.write(UnixTime::<u32>::new(SystemTime::UNIX_EPOCH + Duration::from_millis(1500))).unwrap();
+	assert_eq!(writer, b"\x01\x00\x00\x00");
+	```
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UnixTime<T = i64> {
+	inner: SystemTime,
+	int_type: PhantomData<T>,
+}
+
+/// A point in time, stored as the number of milliseconds since the Unix epoch as an integer `T`. Otherwise the same as [`UnixTime`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UnixTimeMillis<T = i64> {
+	inner: SystemTime,
+	int_type: PhantomData<T>,
+}
+
+/**
+	A point in time, stored as a Windows `FILETIME`.
+
+	The time is (de-)serialized as a `u64` in the stream's endianness, counting 100 nanosecond intervals since 1601-01-01 00:00:00 UTC. Writing rounds down to whole intervals, and returns an `InvalidInput` error for times before 1601. Reading returns an `InvalidData` error if the time can't be represented as a `SystemTime` on this platform.
+
+	## Examples
+
+	```
+	use std::time::SystemTime;
+	use endio::{FileTime, LERead};
+
+	let mut reader = &b"\x00\x80\x3e\xd5\xde\xb1\x9d\x01"[..];
+	let val: FileTime = reader.read().unwrap();
+	assert_eq!(val.0, SystemTime::UNIX_EPOCH);
+	```
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FileTime(pub SystemTime);
+
+/**
+	A point in time, stored as a packed MS-DOS time and date.
+
+	The time is (de-)serialized as two `u16`s in the stream's endianness, first the time and then the date, like in ZIP files and FAT directory entries. The time holds the hour (5 bits), minute (6 bits) and seconds divided by 2 (5 bits), and the date holds the year since 1980 (7 bits), month (4 bits) and day (5 bits). DOS times don't have a time zone, and are treated as UTC.
+
+	Reading returns an `InvalidData` error if any of the fields is out of range, like a month of 0. Writing rounds down to an even number of seconds, and returns an `InvalidInput` error if the year is not between 1980 and 2107.
+
+	## Examples
+
+	```
+	use std::time::{Duration, SystemTime};
+	use endio::{DosDateTime, LERead, LEWrite};
+
+	let mut reader = &b"\x00\x00\x21\x00"[..];
+	let val: DosDateTime = reader.read().unwrap();
+	assert_eq!(val.0, SystemTime::UNIX_EPOCH + Duration::from_secs(315532800));
+
+	let mut writer = vec![];
+	writer.write(val).unwrap();
+	assert_eq!(writer, b"\x00\x00\x21\x00");
+	```
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DosDateTime(pub SystemTime);
+
+/**
+	A point in time, stored as an NTP timestamp.
+
+	The time is (de-)serialized as two `u32`s in the stream's endianness, the seconds since 1900-01-01 00:00:00 UTC and the fraction of a second in units of 2^-32 seconds. NTP itself uses big endian. Timestamps are interpreted as being in NTP era 0, so they cover the years 1900 to 2036.
+
+	Writing returns an `InvalidInput` error for times outside of era 0. Reading returns an `InvalidData` error if the time can't be represented as a `SystemTime` on this platform.
+
+	## Examples
+
+	```
+	use std::time::{Duration, SystemTime};
+	use endio::{BERead, BEWrite, NtpTime};
+
+	let mut reader = &b"\x83\xaa\x7e\x80\x80\x00\x00\x00"[..];
+	let val: NtpTime = reader.read().unwrap();
+	assert_eq!(val.0, SystemTime::UNIX_EPOCH + Duration::from_millis(500));
+
+	let mut writer = vec![];
+	writer.write(val).unwrap();
+	assert_eq!(writer, b"\x83\xaa\x7e\x80\x80\x00\x00\x00");
+	```
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NtpTime(pub SystemTime);
+
+/**
+	A point in time, stored as an HFS+ date.
+
+	The time is (de-)serialized as a `u32` in the stream's endianness, counting the seconds since 1904-01-01 00:00:00 UTC. Writing rounds down to whole seconds, and returns an `InvalidInput` error if the time doesn't fit. Reading returns an `InvalidData` error if the time can't be represented as a `SystemTime` on this platform.
+
+	## Examples
+
+	```
+	use std::time::SystemTime;
+	use endio::{BERead, HfsTime};
+
+	let mut reader = &b"\x7c\x25\xb0\x80"[..];
+	let val: HfsTime = reader.read().unwrap();
+	assert_eq!(val.0, SystemTime::UNIX_EPOCH);
+	```
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HfsTime(pub SystemTime);
+
+fn out_of_range_read() -> io::Error {
+	io::Error::new(io::ErrorKind::InvalidData, "timestamp is out of range")
+}
+
+fn out_of_range_write() -> io::Error {
+	io::Error::new(io::ErrorKind::InvalidInput, "time does not fit in timestamp type")
+}
+
+/// Converts nanoseconds relative to the Unix epoch to a `SystemTime`, returning an `InvalidData` error if it can't be represented.
+fn from_unix_nanos(nanos: i128) -> Res<SystemTime> {
+	let abs = nanos.unsigned_abs();
+	let secs = u64::try_from(abs / NANOS_PER_SEC as u128).map_err(|_| out_of_range_read())?;
+	let duration = Duration::new(secs, (abs % NANOS_PER_SEC as u128) as u32);
+	let time = if nanos >= 0 {
+		SystemTime::UNIX_EPOCH.checked_add(duration)
+	} else {
+		SystemTime::UNIX_EPOCH.checked_sub(duration)
+	};
+	time.ok_or_else(out_of_range_read)
+}
+
+/// Converts a `SystemTime` to nanoseconds relative to the Unix epoch.
+fn to_unix_nanos(time: SystemTime) -> i128 {
+	match time.duration_since(SystemTime::UNIX_EPOCH) {
+		Ok(duration) => duration.as_nanos() as i128,
+		Err(err) => -(err.duration().as_nanos() as i128),
+	}
+}
+
+/// Converts a count of `units_per_sec` units since an epoch `epoch` seconds before the Unix epoch to a `SystemTime`.
+fn from_units(units: i128, units_per_sec: i128, epoch: i128) -> Res<SystemTime> {
+	from_unix_nanos((units - epoch * units_per_sec) * (NANOS_PER_SEC / units_per_sec))
+}
+
+/// Converts a `SystemTime` to a count of `units_per_sec` units since an epoch `epoch` seconds before the Unix epoch, rounding down.
+fn to_units<T: TryFrom<i128>>(time: SystemTime, units_per_sec: i128, epoch: i128) -> Res<T> {
+	let units = to_unix_nanos(time).div_euclid(NANOS_PER_SEC / units_per_sec) + epoch * units_per_sec;
+	T::try_from(units).map_err(|_| out_of_range_write())
+}
+
+/// Returns the number of days since 1970-01-01 of a date in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+	let year = if month <= 2 { year - 1 } else { year };
+	let era = year.div_euclid(400);
+	let year_of_era = year.rem_euclid(400);
+	let month_index = (month as i64 + 9) % 12;
+	let day_of_year = (153 * month_index + 2) / 5 + day as i64 - 1;
+	let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+	era * 146_097 + day_of_era - 719_468
+}
+
+/// Returns the year, month and day of a number of days since 1970-01-01 in the proleptic Gregorian calendar.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+	let days = days + 719_468;
+	let era = days.div_euclid(146_097);
+	let day_of_era = days.rem_euclid(146_097);
+	let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+	let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+	let month_index = (5 * day_of_year + 2) / 153;
+	let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+	let month = if month_index < 10 { month_index + 3 } else { month_index - 9 } as u32;
+	let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+	(year, month, day)
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+	match month {
+		2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+		2 => 28,
+		4 | 6 | 9 | 11 => 30,
+		_ => 31,
+	}
+}
+
+macro_rules! impl_unix_time {
+	($t:ident, $units_per_sec:expr) => {
+		impl<T> $t<T> {
+			/// Wraps the time.
+			pub fn new(inner: SystemTime) -> Self {
+				Self { inner, int_type: PhantomData }
+			}
+
+			/// Unwraps the time.
+			pub fn into_inner(self) -> SystemTime {
+				self.inner
+			}
+		}
+
+		impl<T> From<SystemTime> for $t<T> {
+			fn from(inner: SystemTime) -> Self {
+				Self::new(inner)
+			}
+		}
+
+		impl<T> Deref for $t<T> {
+			type Target = SystemTime;
+
+			fn deref(&self) -> &SystemTime {
+				&self.inner
+			}
+		}
+
+		impl<T> DerefMut for $t<T> {
+			fn deref_mut(&mut self) -> &mut SystemTime {
+				&mut self.inner
+			}
+		}
+
+		impl<E: Endianness, R: ERead<E>, T> Deserialize<E, R> for $t<T> where T: Deserialize<E, R> + Into<i128> {
+			fn deserialize(reader: &mut R) -> Res<Self> {
+				let value: T = reader.read()?;
+				Ok(Self::new(from_units(value.into(), $units_per_sec, 0)?))
+			}
+		}
+
+		impl<E: Endianness, W: EWrite<E>, T> Serialize<E, W> for $t<T> where T: Serialize<E, W> + TryFrom<i128> {
+			fn serialize(self, writer: &mut W) -> Res<()> {
+				writer.write(to_units::<T>(self.inner, $units_per_sec, 0)?)
+			}
+		}
+
+		impl<E: Endianness, W: EWrite<E>, T> Serialize<E, W> for &$t<T> where $t<T>: Serialize<E, W> + Copy {
+			fn serialize(self, writer: &mut W) -> Res<()> {
+				writer.write(*self)
+			}
+		}
+	}
+}
+
+impl_unix_time!(UnixTime, 1);
+impl_unix_time!(UnixTimeMillis, 1000);
+
+macro_rules! impl_timestamp_common {
+	($t:ident) => {
+		impl From<SystemTime> for $t {
+			fn from(time: SystemTime) -> Self {
+				Self(time)
+			}
+		}
+
+		impl From<$t> for SystemTime {
+			fn from(time: $t) -> Self {
+				time.0
+			}
+		}
+
+		impl<E: Endianness, W: EWrite<E>> Serialize<E, W> for &$t where $t: Serialize<E, W> {
+			fn serialize(self, writer: &mut W) -> Res<()> {
+				writer.write(*self)
+			}
+		}
+	}
+}
+
+impl_timestamp_common!(FileTime);
+impl_timestamp_common!(DosDateTime);
+impl_timestamp_common!(NtpTime);
+impl_timestamp_common!(HfsTime);
+
+impl<E: Endianness, R: ERead<E>> Deserialize<E, R> for FileTime where u64: Deserialize<E, R> {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let ticks: u64 = reader.read()?;
+		Ok(Self(from_units(ticks.into(), 10_000_000, FILETIME_EPOCH)?))
+	}
+}
+
+impl<E: Endianness, W: EWrite<E>> Serialize<E, W> for FileTime where u64: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		writer.write(to_units::<u64>(self.0, 10_000_000, FILETIME_EPOCH)?)
+	}
+}
+
+impl<E: Endianness, R: ERead<E>> Deserialize<E, R> for HfsTime where u32: Deserialize<E, R> {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let secs: u32 = reader.read()?;
+		Ok(Self(from_units(secs.into(), 1, HFS_EPOCH)?))
+	}
+}
+
+impl<E: Endianness, W: EWrite<E>> Serialize<E, W> for HfsTime where u32: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		writer.write(to_units::<u32>(self.0, 1, HFS_EPOCH)?)
+	}
+}
+
+/// Reads the seconds, then the fraction.
+impl<E: Endianness, R: ERead<E>> Deserialize<E, R> for NtpTime where u32: Deserialize<E, R> {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let secs: u32 = reader.read()?;
+		let frac: u32 = reader.read()?;
+		let nanos = (frac as i128 * NANOS_PER_SEC) >> 32;
+		Ok(Self(from_unix_nanos((secs as i128 - NTP_EPOCH) * NANOS_PER_SEC + nanos)?))
+	}
+}
+
+/// Writes the seconds, then the fraction, rounded so that reading it back gives the same time.
+impl<E: Endianness, W: EWrite<E>> Serialize<E, W> for NtpTime where u32: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		let nanos = to_unix_nanos(self.0) + NTP_EPOCH * NANOS_PER_SEC;
+		let secs = u32::try_from(nanos.div_euclid(NANOS_PER_SEC)).map_err(|_| out_of_range_write())?;
+		let frac = ((nanos.rem_euclid(NANOS_PER_SEC) << 32) + NANOS_PER_SEC - 1) / NANOS_PER_SEC;
+		writer.write(secs)?;
+		writer.write(frac as u32)
+	}
+}
+
+/// Reads the time, then the date, returning an `InvalidData` error if any of the fields is out of range.
+impl<E: Endianness, R: ERead<E>> Deserialize<E, R> for DosDateTime where u16: Deserialize<E, R> {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let time: u16 = reader.read()?;
+		let date: u16 = reader.read()?;
+		let year = 1980 + (date >> 9) as i64;
+		let month = ((date >> 5) & 0xf) as u32;
+		let day = (date & 0x1f) as u32;
+		let hour = (time >> 11) as i64;
+		let minute = ((time >> 5) & 0x3f) as i64;
+		let second = (time & 0x1f) as i64 * 2;
+		if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) || hour > 23 || minute > 59 || second > 59 {
+			return Err(io::Error::new(io::ErrorKind::InvalidData, "DOS date/time had field out of range"));
+		}
+		let secs = days_from_civil(year, month, day) * 86400 + hour * 3600 + minute * 60 + second;
+		Ok(Self(from_units(secs.into(), 1, 0)?))
+	}
+}
+
+/// Writes the time, then the date.
+impl<E: Endianness, W: EWrite<E>> Serialize<E, W> for DosDateTime where u16: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		let secs = to_units::<i64>(self.0, 1, 0)?;
+		let (year, month, day) = civil_from_days(secs.div_euclid(86400));
+		if !(1980..=2107).contains(&year) {
+			return Err(out_of_range_write());
+		}
+		let secs_of_day = secs.rem_euclid(86400);
+		let time = ((secs_of_day / 3600) << 11) | ((secs_of_day / 60 % 60) << 5) | (secs_of_day % 60 / 2);
+		let date = ((year - 1980) << 9) | ((month as i64) << 5) | day as i64;
+		writer.write(time as u16)?;
+		writer.write(date as u16)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::io;
+	use std::time::{Duration, SystemTime};
+	use crate::{DosDateTime, FileTime, HfsTime, NtpTime, UnixTime, UnixTimeMillis};
+
+	fn unix(secs: i64, nanos: u32) -> SystemTime {
+		if secs >= 0 {
+			SystemTime::UNIX_EPOCH + Duration::new(secs as u64, nanos)
+		} else {
+			SystemTime::UNIX_EPOCH - Duration::from_secs(-secs as u64) + Duration::from_nanos(nanos.into())
+		}
+	}
+
+	#[test]
+	fn read_unix_time() {
+		let data = b"\xff\xff\xff\xff\x00\x00\x00\x00\x00\x00\x03\xe8";
+		use crate::BERead;
+		let mut reader = &data[..];
+		let val: UnixTime<i32> = reader.read().unwrap();
+		assert_eq!(*val, unix(-1, 0));
+		let val: UnixTimeMillis<u64> = reader.read().unwrap();
+		assert_eq!(*val, unix(1, 0));
+	}
+
+	#[test]
+	fn write_unix_time() {
+		let data = b"\xff\xff\xff\xff\xff\xff\xff\xff\xf4\x01\x00\x00";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(UnixTime::<i64>::new(unix(-1, 500_000_000))).unwrap();
+		writer.write(&[UnixTimeMillis::<u32>::new(unix(0, 500_999_999))][..]).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn write_unix_time_out_of_range() {
+		use crate::LEWrite;
+		let mut writer = vec![];
+		let err = writer.write(UnixTime::<u32>::new(unix(-1, 0))).unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+		let err = writer.write(UnixTime::<i32>::new(unix(1 << 31, 0))).unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+		assert!(writer.is_empty());
+	}
+
+	#[test]
+	fn file_time() {
+		let data = b"\x01\x80\x3e\xd5\xde\xb1\x9d\x01\x00\x00\x00\x00\x00\x00\x00\x00";
+		{
+			use crate::LERead;
+			let mut reader = &data[..];
+			let val: FileTime = reader.read().unwrap();
+			assert_eq!(val.0, unix(0, 100));
+		}
+		{
+			use crate::LEWrite;
+			let mut writer = vec![];
+			writer.write(FileTime(unix(0, 199))).unwrap();
+			writer.write(FileTime(unix(-11_644_473_600, 0))).unwrap();
+			assert_eq!(writer, data);
+			let err = writer.write(FileTime(unix(-11_644_473_601, 0))).unwrap_err();
+			assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+		}
+	}
+
+	#[test]
+	fn dos_date_time() {
+		// 2024-02-29 13:37:42
+		let data = b"\xb5\x6c\x5d\x58";
+		let time = unix(1_709_213_862, 0);
+		{
+			use crate::LERead;
+			let mut reader = &data[..];
+			let val: DosDateTime = reader.read().unwrap();
+			assert_eq!(val.0, time);
+		}
+		{
+			use crate::LEWrite;
+			let mut writer = vec![];
+			writer.write(DosDateTime(time + Duration::from_millis(1999))).unwrap();
+			assert_eq!(writer, data);
+			let err = writer.write(DosDateTime(unix(315_532_799, 0))).unwrap_err();
+			assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+		}
+	}
+
+	#[test]
+	fn dos_date_time_invalid() {
+		use crate::LERead;
+		// 2023-02-29
+		let mut reader = &b"\x00\x00\x5d\x56"[..];
+		assert_eq!(reader.read::<DosDateTime>().unwrap_err().kind(), io::ErrorKind::InvalidData);
+		// month 0
+		let mut reader = &b"\x00\x00\x01\x00"[..];
+		assert_eq!(reader.read::<DosDateTime>().unwrap_err().kind(), io::ErrorKind::InvalidData);
+		// 24:00
+		let mut reader = &b"\x00\xc0\x21\x00"[..];
+		assert_eq!(reader.read::<DosDateTime>().unwrap_err().kind(), io::ErrorKind::InvalidData);
+	}
+
+	#[test]
+	fn ntp_time() {
+		let data = b"\x00\x00\x00\x00\x40\x00\x00\x00";
+		{
+			use crate::BERead;
+			let mut reader = &data[..];
+			let val: NtpTime = reader.read().unwrap();
+			assert_eq!(val.0, unix(-2_208_988_800, 250_000_000));
+		}
+		{
+			use crate::BEWrite;
+			let mut writer = vec![];
+			writer.write(NtpTime(unix(-2_208_988_800, 250_000_000))).unwrap();
+			assert_eq!(writer, data);
+			let err = writer.write(NtpTime(unix(2_085_978_496, 0))).unwrap_err();
+			assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+		}
+		for nanos in [1, 999_999_999, 123_456_789] {
+			use crate::LERead;
+			use crate::LEWrite;
+			let mut writer = vec![];
+			writer.write(NtpTime(unix(1, nanos))).unwrap();
+			let mut reader = &writer[..];
+			assert_eq!(reader.read::<NtpTime>().unwrap().0, unix(1, nanos));
+		}
+	}
+
+	#[test]
+	fn hfs_time() {
+		let data = b"\x00\x00\x00\x00";
+		use crate::BEWrite;
+		let mut writer = vec![];
+		writer.write(HfsTime(unix(-2_082_844_800, 999))).unwrap();
+		assert_eq!(writer, data);
+	}
+}
@@ -0,0 +1,1157 @@
+use core::convert::TryFrom;
+use core::num::TryFromIntError;
+
+use crate::io;
+use crate::io::Result as Res;
+use crate::{ByteRead, ByteWrite, Deserialize, Endianness, Serialize};
+
+/**
+	An unsigned 32-bit integer, encoded as unsigned LEB128.
+
+	Each byte holds 7 bits of the value, least significant group first, with the high bit set on all bytes except the last. This is the encoding used by WebAssembly, DWARF and protobuf. Since the encoding is byte-oriented, it is the same regardless of the stream's endianness.
+
+	Reading returns an `InvalidData` error if the encoding is overlong (has unnecessary trailing zero groups) or if the value does not fit in the type.
+
+	Like all LEB128 types, this can be used as the length type of [`LenPrefixed`](crate::LenPrefixed).
+
+	## Examples
+
+	```
+	use endio::{LERead, LEWrite, LenPrefixed, VarU32};
+
+	let mut reader = &b"\xe5\x8e\x26"[..];
+	let val: VarU32 = reader.read().unwrap();
+	assert_eq!(val.0, 624485);
+
+	let mut writer = vec![];
+	writer.write(LenPrefixed::<VarU32, _>::new(&[1u8; 200][..])).unwrap();
+	assert_eq!(&writer[..2], b"\xc8\x01");
+	```
+*/
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VarU32(pub u32);
+
+/// An unsigned 64-bit integer, encoded as unsigned LEB128. See [`VarU32`] for details.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VarU64(pub u64);
+
+/**
+	A signed 32-bit integer, encoded as signed LEB128.
+
+	Like [`VarU32`], but the last byte's highest value bit is sign-extended, as used by WebAssembly and DWARF. Reading returns an `InvalidData` error if the encoding is overlong or if the value does not fit in the type.
+
+	## Examples
+
+	```
+	use endio::{BERead, BEWrite, VarI32};
+
+	let mut reader = &b"\xc0\xbb\x78"[..];
+	let val: VarI32 = reader.read().unwrap();
+	assert_eq!(val.0, -123456);
+
+	let mut writer = vec![];
+	writer.write(VarI32(-1)).unwrap();
+	assert_eq!(writer, b"\x7f");
+	```
+*/
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VarI32(pub i32);
+
+/// A signed 64-bit integer, encoded as signed LEB128. See [`VarI32`] for details.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VarI64(pub i64);
+
+/**
+	A signed 32-bit integer, zigzag encoded and then encoded as unsigned LEB128.
+
+	Zigzag encoding maps signed integers to unsigned ones so that values with a small magnitude have a short encoding: 0 → 0, -1 → 1, 1 → 2, -2 → 3, and so on. This is the encoding protobuf uses for `sint32` and `sint64`. Reading returns an `InvalidData` error if the encoding is overlong or if the value does not fit in the type.
+
+	## Examples
+
+	```
+	use endio::{LERead, LEWrite, ZigZagI32};
+
+	let mut reader = &b"\x03"[..];
+	let val: ZigZagI32 = reader.read().unwrap();
+	assert_eq!(val.0, -2);
+
+	let mut writer = vec![];
+	writer.write(ZigZagI32(-65)).unwrap();
+	assert_eq!(writer, b"\x81\x01");
+	```
+*/
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ZigZagI32(pub i32);
+
+/// A signed 64-bit integer, zigzag encoded and then encoded as unsigned LEB128. See [`ZigZagI32`] for details.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ZigZagI64(pub i64);
+
+/**
+	An unsigned 64-bit integer, encoded as a big endian variable-length quantity (VLQ).
+
+	Each byte holds 7 bits of the value, most significant group first, with the high bit set on all bytes except the last. This is the encoding used for delta times in MIDI files. Since the encoding is byte-oriented, it is the same regardless of the stream's endianness.
+
+	Reading returns an `InvalidData` error if the encoding is overlong (has unnecessary leading zero groups) or if the value does not fit in a `u64`.
+
+	## Examples
+
+	```
+	use endio::{BERead, BEWrite, Vlq};
+
+	let mut reader = &b"\x81\x80\x00"[..];
+	let val: Vlq = reader.read().unwrap();
+	assert_eq!(val.0, 0x4000);
+
+	let mut writer = vec![];
+	writer.write(Vlq(0x7f)).unwrap();
+	assert_eq!(writer, b"\x7f");
+	```
+*/
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vlq(pub u64);
+
+/**
+	An unsigned 64-bit integer, encoded as a big endian VLQ where every continuation also adds one.
+
+	Like [`Vlq`], but the value of each group except the last is incremented by one before being shifted, which makes every value have exactly one encoding. This is the encoding git uses for offsets in pack files. Reading returns an `InvalidData` error if the value does not fit in a `u64`.
+
+	## Examples
+
+	```
+	use endio::{BERead, BEWrite, BijectiveVlq};
+
+	let mut reader = &b"\x80\x00"[..];
+	let val: BijectiveVlq = reader.read().unwrap();
+	assert_eq!(val.0, 128);
+
+	let mut writer = vec![];
+	writer.write(BijectiveVlq(127)).unwrap();
+	assert_eq!(writer, b"\x7f");
+	```
+*/
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BijectiveVlq(pub u64);
+
+/**
+	An unsigned 64-bit integer, encoded as an SQLite varint.
+
+	The value is stored in 1 to 9 bytes, most significant first. The first 8 bytes each hold 7 bits of the value, with the high bit set if another byte follows. A ninth byte, if present, holds 8 bits. Since the encoding is byte-oriented, it is the same regardless of the stream's endianness.
+
+	Reading returns an `InvalidData` error if the encoding is overlong (has unnecessary leading zero groups).
+
+	## Examples
+
+	```
+	use endio::{LERead, LEWrite, SqliteVarint};
+
+	let mut reader = &b"\x81\x00"[..];
+	let val: SqliteVarint = reader.read().unwrap();
+	assert_eq!(val.0, 128);
+
+	let mut writer = vec![];
+	writer.write(SqliteVarint(u64::MAX)).unwrap();
+	assert_eq!(writer, b"\xff\xff\xff\xff\xff\xff\xff\xff\xff");
+	```
+*/
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SqliteVarint(pub u64);
+
+/**
+	An unsigned 64-bit integer, encoded as a Bitcoin CompactSize.
+
+	Values below `0xfd` are stored as a single byte. Larger values are stored as a marker byte of `0xfd`, `0xfe` or `0xff`, followed by the value as a `u16`, `u32` or `u64` respectively. The value is always in little endian, as in the Bitcoin protocol, regardless of the stream's endianness.
+
+	Reading returns an `InvalidData` error if the encoding is not the shortest possible one, like Bitcoin Core does.
+
+	## Examples
+
+	```
+	use endio::{CompactSize, LERead, LEWrite, LenPrefixed};
+
+	let mut reader = &b"\xfd\x00\x01"[..];
+	let val: CompactSize = reader.read().unwrap();
+	assert_eq!(val.0, 256);
+
+	let mut writer = vec![];
+	writer.write(LenPrefixed::<CompactSize, _>::new(&b"abc"[..])).unwrap();
+	assert_eq!(writer, b"\x03abc");
+	```
+*/
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CompactSize(pub u64);
+
+/**
+	An unsigned 62-bit integer, encoded as a QUIC variable-length integer.
+
+	The two most significant bits of the first byte specify the length of the encoding as 1, 2, 4 or 8 bytes, and the remaining bits hold the value in big endian, regardless of the stream's endianness.
+
+	As allowed by RFC 9000, reading accepts encodings that are longer than necessary. Writing always uses the shortest encoding, and returns an `InvalidInput` error if the value does not fit in 62 bits.
+
+	## Examples
+
+	```
+	use endio::{BERead, BEWrite, QuicVarint};
+
+	let mut reader = &b"\x7b\xbd"[..];
+	let val: QuicVarint = reader.read().unwrap();
+	assert_eq!(val.0, 15293);
+
+	let mut writer = vec![];
+	writer.write(QuicVarint(37)).unwrap();
+	assert_eq!(writer, b"\x25");
+	```
+*/
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct QuicVarint(pub u64);
+
+fn read_byte<R: ByteRead>(reader: &mut R) -> Res<u8> {
+	let mut buf = [0; 1];
+	reader.read_exact(&mut buf)?;
+	Ok(buf[0])
+}
+
+fn overflow_error() -> io::Error {
+	io::Error::new(io::ErrorKind::InvalidData, "varint value does not fit in its type")
+}
+
+fn overlong_error() -> io::Error {
+	io::Error::new(io::ErrorKind::InvalidData, "varint encoding was overlong")
+}
+
+/// Reads an unsigned LEB128 value of at most `bits` bits.
+fn read_uleb128<R: ByteRead>(reader: &mut R, bits: u32) -> Res<u64> {
+	let mut value = 0;
+	let mut shift = 0;
+	loop {
+		let byte = read_byte(reader)?;
+		let group = (byte & 0x7f) as u64;
+		if shift + 7 > bits && (byte & 0x80 != 0 || group >> (bits - shift) != 0) {
+			return Err(overflow_error());
+		}
+		value |= group << shift;
+		if byte & 0x80 == 0 {
+			if byte == 0 && shift > 0 {
+				return Err(overlong_error());
+			}
+			return Ok(value);
+		}
+		shift += 7;
+	}
+}
+
+/// Reads a signed LEB128 value of at most `bits` bits.
+fn read_sleb128<R: ByteRead>(reader: &mut R, bits: u32) -> Res<i64> {
+	let mut value = 0;
+	let mut shift = 0;
+	let mut prev = 0;
+	loop {
+		let byte = read_byte(reader)?;
+		let group = byte & 0x7f;
+		if shift + 7 > bits {
+			// the bits above the value's width must all be copies of its sign bit
+			let high = group >> (bits - shift - 1);
+			if byte & 0x80 != 0 || (high != 0 && high != 0x7f >> (bits - shift - 1)) {
+				return Err(overflow_error());
+			}
+		}
+		value |= (group as i64) << shift;
+		shift += 7;
+		if byte & 0x80 == 0 {
+			if shift > 7 && ((byte == 0 && prev & 0x40 == 0) || (byte == 0x7f && prev & 0x40 != 0)) {
+				return Err(overlong_error());
+			}
+			if shift < 64 && byte & 0x40 != 0 {
+				value |= !0 << shift;
+			}
+			return Ok(value);
+		}
+		prev = byte;
+	}
+}
+
+fn write_uleb128<W: ByteWrite>(writer: &mut W, mut value: u64) -> Res<()> {
+	loop {
+		let mut byte = (value & 0x7f) as u8;
+		value >>= 7;
+		if value != 0 {
+			byte |= 0x80;
+		}
+		writer.write_all(&[byte])?;
+		if value == 0 {
+			return Ok(());
+		}
+	}
+}
+
+fn write_sleb128<W: ByteWrite>(writer: &mut W, mut value: i64) -> Res<()> {
+	loop {
+		let mut byte = (value & 0x7f) as u8;
+		value >>= 7;
+		let done = (value == 0 && byte & 0x40 == 0) || (value == -1 && byte & 0x40 != 0);
+		if !done {
+			byte |= 0x80;
+		}
+		writer.write_all(&[byte])?;
+		if done {
+			return Ok(());
+		}
+	}
+}
+
+fn zigzag_encode(value: i64) -> u64 {
+	((value << 1) ^ (value >> 63)) as u64
+}
+
+fn zigzag_decode(value: u64) -> i64 {
+	(value >> 1) as i64 ^ -((value & 1) as i64)
+}
+
+/// Implements conversions and a by-reference `Serialize` impl for a varint wrapper type.
+macro_rules! impl_varint_common {
+	($t:ident, $int:ident) => {
+		impl From<$int> for $t {
+			fn from(value: $int) -> Self {
+				Self(value)
+			}
+		}
+
+		impl From<$t> for $int {
+			fn from(value: $t) -> Self {
+				value.0
+			}
+		}
+
+		impl<E: Endianness, W> Serialize<E, W> for &$t where $t: Serialize<E, W> {
+			fn serialize(self, writer: &mut W) -> Res<()> {
+				(*self).serialize(writer)
+			}
+		}
+	}
+}
+
+/// Implements conversions from and to `usize`, so that the type can be used as a length type.
+macro_rules! impl_varint_len {
+	($t:ident, $int:ident) => {
+		impl TryFrom<usize> for $t {
+			type Error = TryFromIntError;
+
+			fn try_from(value: usize) -> Result<Self, TryFromIntError> {
+				$int::try_from(value).map(Self)
+			}
+		}
+
+		impl TryFrom<$t> for usize {
+			type Error = TryFromIntError;
+
+			fn try_from(value: $t) -> Result<Self, TryFromIntError> {
+				usize::try_from(value.0)
+			}
+		}
+	}
+}
+
+macro_rules! impl_leb128 {
+	($t:ident, $int:ident, $read:ident, $write:ident, $wide:ident) => {
+		impl<E: Endianness, R: ByteRead> Deserialize<E, R> for $t {
+			fn deserialize(reader: &mut R) -> Res<Self> {
+				Ok(Self($read(reader, $int::BITS)? as $int))
+			}
+		}
+
+		impl<E: Endianness, W: ByteWrite> Serialize<E, W> for $t {
+			fn serialize(self, writer: &mut W) -> Res<()> {
+				$write(writer, self.0 as $wide)
+			}
+		}
+
+		impl_varint_common!($t, $int);
+		impl_varint_len!($t, $int);
+	}
+}
+
+impl_leb128!(VarU32, u32, read_uleb128, write_uleb128, u64);
+impl_leb128!(VarU64, u64, read_uleb128, write_uleb128, u64);
+impl_leb128!(VarI32, i32, read_sleb128, write_sleb128, i64);
+impl_leb128!(VarI64, i64, read_sleb128, write_sleb128, i64);
+
+macro_rules! impl_zigzag {
+	($t:ident, $int:ident) => {
+		impl<E: Endianness, R: ByteRead> Deserialize<E, R> for $t {
+			fn deserialize(reader: &mut R) -> Res<Self> {
+				Ok(Self(zigzag_decode(read_uleb128(reader, $int::BITS)?) as $int))
+			}
+		}
+
+		impl<E: Endianness, W: ByteWrite> Serialize<E, W> for $t {
+			fn serialize(self, writer: &mut W) -> Res<()> {
+				write_uleb128(writer, zigzag_encode(self.0 as i64))
+			}
+		}
+
+		impl_varint_common!($t, $int);
+		impl_varint_len!($t, $int);
+	}
+}
+
+impl_zigzag!(ZigZagI32, i32);
+impl_zigzag!(ZigZagI64, i64);
+
+impl<E: Endianness, R: ByteRead> Deserialize<E, R> for Vlq {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let mut value = 0u64;
+		let mut first = true;
+		loop {
+			let byte = read_byte(reader)?;
+			if first && byte == 0x80 {
+				return Err(overlong_error());
+			}
+			if value >> 57 != 0 {
+				return Err(overflow_error());
+			}
+			value = value << 7 | (byte & 0x7f) as u64;
+			if byte & 0x80 == 0 {
+				return Ok(Self(value));
+			}
+			first = false;
+		}
+	}
+}
+
+impl<E: Endianness, W: ByteWrite> Serialize<E, W> for Vlq {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		let mut buf = [0; 10];
+		let mut start = buf.len() - 1;
+		let mut value = self.0;
+		buf[start] = (value & 0x7f) as u8;
+		value >>= 7;
+		while value != 0 {
+			start -= 1;
+			buf[start] = (value & 0x7f) as u8 | 0x80;
+			value >>= 7;
+		}
+		writer.write_all(&buf[start..])
+	}
+}
+
+impl<E: Endianness, R: ByteRead> Deserialize<E, R> for BijectiveVlq {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let mut byte = read_byte(reader)?;
+		let mut value = (byte & 0x7f) as u64;
+		while byte & 0x80 != 0 {
+			byte = read_byte(reader)?;
+			value = value.checked_add(1)
+				.and_then(|x| x.checked_mul(0x80))
+				.ok_or_else(overflow_error)?
+				| (byte & 0x7f) as u64;
+		}
+		Ok(Self(value))
+	}
+}
+
+impl<E: Endianness, W: ByteWrite> Serialize<E, W> for BijectiveVlq {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		let mut buf = [0; 10];
+		let mut start = buf.len() - 1;
+		let mut value = self.0;
+		buf[start] = (value & 0x7f) as u8;
+		value >>= 7;
+		while value != 0 {
+			value -= 1;
+			start -= 1;
+			buf[start] = (value & 0x7f) as u8 | 0x80;
+			value >>= 7;
+		}
+		writer.write_all(&buf[start..])
+	}
+}
+
+impl<E: Endianness, R: ByteRead> Deserialize<E, R> for SqliteVarint {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let mut value = 0u64;
+		let mut first = 0;
+		for i in 0..8 {
+			let byte = read_byte(reader)?;
+			if i == 0 {
+				first = byte;
+			}
+			value = value << 7 | (byte & 0x7f) as u64;
+			if byte & 0x80 == 0 {
+				if first == 0x80 {
+					return Err(overlong_error());
+				}
+				return Ok(Self(value));
+			}
+		}
+		value = value << 8 | read_byte(reader)? as u64;
+		// the 9 byte form is only necessary for values that don't fit in 8 bytes
+		if value >> 56 == 0 {
+			return Err(overlong_error());
+		}
+		Ok(Self(value))
+	}
+}
+
+impl<E: Endianness, W: ByteWrite> Serialize<E, W> for SqliteVarint {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		let mut value = self.0;
+		if value >> 56 != 0 {
+			let mut buf = [0; 9];
+			buf[8] = value as u8;
+			value >>= 8;
+			for byte in buf[..8].iter_mut().rev() {
+				*byte = (value & 0x7f) as u8 | 0x80;
+				value >>= 7;
+			}
+			return writer.write_all(&buf);
+		}
+		let mut buf = [0; 8];
+		let mut start = buf.len() - 1;
+		buf[start] = (value & 0x7f) as u8;
+		value >>= 7;
+		while value != 0 {
+			start -= 1;
+			buf[start] = (value & 0x7f) as u8 | 0x80;
+			value >>= 7;
+		}
+		writer.write_all(&buf[start..])
+	}
+}
+
+impl<E: Endianness, R: ByteRead> Deserialize<E, R> for CompactSize {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let (value, min) = match read_byte(reader)? {
+			0xfd => {
+				let mut buf = [0; 2];
+				reader.read_exact(&mut buf)?;
+				(u16::from_le_bytes(buf) as u64, 0xfd)
+			}
+			0xfe => {
+				let mut buf = [0; 4];
+				reader.read_exact(&mut buf)?;
+				(u32::from_le_bytes(buf) as u64, 0x1_0000)
+			}
+			0xff => {
+				let mut buf = [0; 8];
+				reader.read_exact(&mut buf)?;
+				(u64::from_le_bytes(buf), 0x1_0000_0000)
+			}
+			byte => return Ok(Self(byte as u64)),
+		};
+		if value < min {
+			return Err(overlong_error());
+		}
+		Ok(Self(value))
+	}
+}
+
+impl<E: Endianness, W: ByteWrite> Serialize<E, W> for CompactSize {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		let value = self.0;
+		if value < 0xfd {
+			writer.write_all(&[value as u8])
+		} else if value <= u16::MAX as u64 {
+			writer.write_all(&[0xfd])?;
+			writer.write_all(&(value as u16).to_le_bytes())
+		} else if value <= u32::MAX as u64 {
+			writer.write_all(&[0xfe])?;
+			writer.write_all(&(value as u32).to_le_bytes())
+		} else {
+			writer.write_all(&[0xff])?;
+			writer.write_all(&value.to_le_bytes())
+		}
+	}
+}
+
+impl<E: Endianness, R: ByteRead> Deserialize<E, R> for QuicVarint {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let first = read_byte(reader)?;
+		let len = 1 << (first >> 6);
+		let mut buf = [0; 8];
+		buf[8 - len] = first & 0x3f;
+		reader.read_exact(&mut buf[9 - len..])?;
+		Ok(Self(u64::from_be_bytes(buf)))
+	}
+}
+
+impl<E: Endianness, W: ByteWrite> Serialize<E, W> for QuicVarint {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		let (len, prefix) = match self.0 {
+			0..=0x3f => (1, 0x00),
+			0x40..=0x3fff => (2, 0x40),
+			0x4000..=0x3fff_ffff => (4, 0x80),
+			0x4000_0000..=0x3fff_ffff_ffff_ffff => (8, 0xc0),
+			_ => return Err(io::Error::new(io::ErrorKind::InvalidInput, "value does not fit in a QUIC varint")),
+		};
+		let mut buf = self.0.to_be_bytes();
+		buf[8 - len] |= prefix;
+		writer.write_all(&buf[8 - len..])
+	}
+}
+
+impl_varint_common!(Vlq, u64);
+impl_varint_len!(Vlq, u64);
+impl_varint_common!(BijectiveVlq, u64);
+impl_varint_len!(BijectiveVlq, u64);
+impl_varint_common!(SqliteVarint, u64);
+impl_varint_len!(SqliteVarint, u64);
+impl_varint_common!(CompactSize, u64);
+impl_varint_len!(CompactSize, u64);
+impl_varint_common!(QuicVarint, u64);
+impl_varint_len!(QuicVarint, u64);
+
+#[cfg(test)]
+mod tests {
+	use crate::io;
+	use crate::{BijectiveVlq, CompactSize, LenPrefixed, LenString, QuicVarint, SqliteVarint, VarI32, VarI64, VarU32, VarU64, Vlq, ZigZagI32, ZigZagI64};
+
+	#[test]
+	fn read_var_u32() {
+		let data = b"\x00\x7f\x80\x01\xe5\x8e\x26\xff\xff\xff\xff\x0f";
+		use crate::LERead;
+		let mut reader = &data[..];
+
+		let mut val: VarU32;
+		val = reader.read().unwrap();
+		assert_eq!(val, VarU32(0));
+		val = reader.read().unwrap();
+		assert_eq!(val, VarU32(127));
+		val = reader.read().unwrap();
+		assert_eq!(val, VarU32(128));
+		val = reader.read().unwrap();
+		assert_eq!(val, VarU32(624485));
+		val = reader.read().unwrap();
+		assert_eq!(val, VarU32(u32::MAX));
+		assert!(reader.is_empty());
+	}
+
+	#[test]
+	fn write_var_u32() {
+		let data = b"\x00\x7f\x80\x01\xe5\x8e\x26\xff\xff\xff\xff\x0f";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(VarU32(0)).unwrap();
+		writer.write(VarU32(127)).unwrap();
+		writer.write(VarU32(128)).unwrap();
+		writer.write(VarU32(624485)).unwrap();
+		writer.write(VarU32(u32::MAX)).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn read_var_u32_overflow() {
+		use crate::LERead;
+		let mut reader = &b"\xff\xff\xff\xff\x1f"[..];
+		let err = reader.read::<VarU32>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+		let mut reader = &b"\xff\xff\xff\xff\x8f\x00"[..];
+		let err = reader.read::<VarU32>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+	}
+
+	#[test]
+	fn read_var_u32_overlong() {
+		use crate::LERead;
+		let mut reader = &b"\x80\x00"[..];
+		let err = reader.read::<VarU32>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+	}
+
+	#[test]
+	fn read_var_u32_eof() {
+		use crate::LERead;
+		let mut reader = &b"\x80\x80"[..];
+		let err = reader.read::<VarU32>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+	}
+
+	#[test]
+	fn read_var_u64() {
+		let data = b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\x01";
+		use crate::LERead;
+		let mut reader = &data[..];
+
+		let val: VarU64 = reader.read().unwrap();
+		assert_eq!(val, VarU64(u64::MAX));
+		assert!(reader.is_empty());
+	}
+
+	#[test]
+	fn write_var_u64() {
+		let data = b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\x01";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(VarU64(u64::MAX)).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn read_var_u64_overflow() {
+		use crate::LERead;
+		let mut reader = &b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\x03"[..];
+		let err = reader.read::<VarU64>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+	}
+
+	#[test]
+	fn read_var_u64_overlong() {
+		use crate::LERead;
+		let mut reader = &b"\xff\x80\x00"[..];
+		let err = reader.read::<VarU64>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+	}
+
+	#[test]
+	fn read_var_i32() {
+		let data = b"\x00\x3f\xc0\x00\x40\xbf\x7f\xc0\xbb\x78\xff\xff\xff\xff\x07\x80\x80\x80\x80\x78";
+		use crate::LERead;
+		let mut reader = &data[..];
+
+		let mut val: VarI32;
+		val = reader.read().unwrap();
+		assert_eq!(val, VarI32(0));
+		val = reader.read().unwrap();
+		assert_eq!(val, VarI32(63));
+		val = reader.read().unwrap();
+		assert_eq!(val, VarI32(64));
+		val = reader.read().unwrap();
+		assert_eq!(val, VarI32(-64));
+		val = reader.read().unwrap();
+		assert_eq!(val, VarI32(-65));
+		val = reader.read().unwrap();
+		assert_eq!(val, VarI32(-123456));
+		val = reader.read().unwrap();
+		assert_eq!(val, VarI32(i32::MAX));
+		val = reader.read().unwrap();
+		assert_eq!(val, VarI32(i32::MIN));
+		assert!(reader.is_empty());
+	}
+
+	#[test]
+	fn write_var_i32() {
+		let data = b"\x00\x3f\xc0\x00\x40\xbf\x7f\xc0\xbb\x78\xff\xff\xff\xff\x07\x80\x80\x80\x80\x78";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(VarI32(0)).unwrap();
+		writer.write(VarI32(63)).unwrap();
+		writer.write(VarI32(64)).unwrap();
+		writer.write(VarI32(-64)).unwrap();
+		writer.write(VarI32(-65)).unwrap();
+		writer.write(VarI32(-123456)).unwrap();
+		writer.write(VarI32(i32::MAX)).unwrap();
+		writer.write(VarI32(i32::MIN)).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn read_var_i32_overflow() {
+		use crate::LERead;
+		let mut reader = &b"\xff\xff\xff\xff\x0f"[..];
+		let err = reader.read::<VarI32>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+		let mut reader = &b"\x80\x80\x80\x80\x70"[..];
+		let err = reader.read::<VarI32>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+	}
+
+	#[test]
+	fn read_var_i32_overlong() {
+		use crate::LERead;
+		let mut reader = &b"\x80\x00"[..];
+		let err = reader.read::<VarI32>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+		let mut reader = &b"\xff\x7f"[..];
+		let err = reader.read::<VarI32>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+	}
+
+	#[test]
+	fn read_var_i64() {
+		let data = b"\x80\x80\x80\x80\x80\x80\x80\x80\x80\x7f\xff\xff\xff\xff\xff\xff\xff\xff\xff\x00";
+		use crate::LERead;
+		let mut reader = &data[..];
+
+		let mut val: VarI64;
+		val = reader.read().unwrap();
+		assert_eq!(val, VarI64(i64::MIN));
+		val = reader.read().unwrap();
+		assert_eq!(val, VarI64(i64::MAX));
+		assert!(reader.is_empty());
+	}
+
+	#[test]
+	fn write_var_i64() {
+		let data = b"\x80\x80\x80\x80\x80\x80\x80\x80\x80\x7f\xff\xff\xff\xff\xff\xff\xff\xff\xff\x00";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(VarI64(i64::MIN)).unwrap();
+		writer.write(VarI64(i64::MAX)).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn read_var_i64_overflow() {
+		use crate::LERead;
+		let mut reader = &b"\x80\x80\x80\x80\x80\x80\x80\x80\x80\x01"[..];
+		let err = reader.read::<VarI64>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+	}
+
+	#[test]
+	fn read_var_i64_overlong() {
+		use crate::LERead;
+		let mut reader = &b"\xc0\x7f"[..];
+		let err = reader.read::<VarI64>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+	}
+
+	#[test]
+	fn read_zigzag_i32() {
+		let data = b"\x00\x01\x02\xfe\xff\xff\xff\x0f\xff\xff\xff\xff\x0f";
+		use crate::LERead;
+		let mut reader = &data[..];
+
+		let mut val: ZigZagI32;
+		val = reader.read().unwrap();
+		assert_eq!(val, ZigZagI32(0));
+		val = reader.read().unwrap();
+		assert_eq!(val, ZigZagI32(-1));
+		val = reader.read().unwrap();
+		assert_eq!(val, ZigZagI32(1));
+		val = reader.read().unwrap();
+		assert_eq!(val, ZigZagI32(i32::MAX));
+		val = reader.read().unwrap();
+		assert_eq!(val, ZigZagI32(i32::MIN));
+		assert!(reader.is_empty());
+	}
+
+	#[test]
+	fn write_zigzag_i32() {
+		let data = b"\x00\x01\x02\xfe\xff\xff\xff\x0f\xff\xff\xff\xff\x0f";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(ZigZagI32(0)).unwrap();
+		writer.write(ZigZagI32(-1)).unwrap();
+		writer.write(ZigZagI32(1)).unwrap();
+		writer.write(ZigZagI32(i32::MAX)).unwrap();
+		writer.write(ZigZagI32(i32::MIN)).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn read_zigzag_i32_overflow() {
+		use crate::LERead;
+		let mut reader = &b"\xff\xff\xff\xff\x1f"[..];
+		let err = reader.read::<ZigZagI32>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+	}
+
+	#[test]
+	fn read_zigzag_i64() {
+		let data = b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\x01";
+		use crate::LERead;
+		let mut reader = &data[..];
+
+		let val: ZigZagI64 = reader.read().unwrap();
+		assert_eq!(val, ZigZagI64(i64::MIN));
+		assert!(reader.is_empty());
+	}
+
+	#[test]
+	fn write_zigzag_i64() {
+		let data = b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\x01";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(ZigZagI64(i64::MIN)).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn read_vlq() {
+		let data = b"\x00\x7f\x81\x00\xc0\x00\xff\xff\xff\x7f\x81\xff\xff\xff\xff\xff\xff\xff\xff\x7f";
+		use crate::LERead;
+		let mut reader = &data[..];
+
+		let mut val: Vlq;
+		val = reader.read().unwrap();
+		assert_eq!(val, Vlq(0));
+		val = reader.read().unwrap();
+		assert_eq!(val, Vlq(0x7f));
+		val = reader.read().unwrap();
+		assert_eq!(val, Vlq(0x80));
+		val = reader.read().unwrap();
+		assert_eq!(val, Vlq(0x2000));
+		val = reader.read().unwrap();
+		assert_eq!(val, Vlq(0x0fff_ffff));
+		val = reader.read().unwrap();
+		assert_eq!(val, Vlq(u64::MAX));
+		assert!(reader.is_empty());
+	}
+
+	#[test]
+	fn write_vlq() {
+		let data = b"\x00\x7f\x81\x00\xc0\x00\xff\xff\xff\x7f\x81\xff\xff\xff\xff\xff\xff\xff\xff\x7f";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(Vlq(0)).unwrap();
+		writer.write(Vlq(0x7f)).unwrap();
+		writer.write(Vlq(0x80)).unwrap();
+		writer.write(Vlq(0x2000)).unwrap();
+		writer.write(Vlq(0x0fff_ffff)).unwrap();
+		writer.write(Vlq(u64::MAX)).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn read_vlq_invalid() {
+		use crate::LERead;
+		let mut reader = &b"\x80\x7f"[..];
+		let err = reader.read::<Vlq>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+		let mut reader = &b"\x82\x80\x80\x80\x80\x80\x80\x80\x80\x00"[..];
+		let err = reader.read::<Vlq>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+	}
+
+	#[test]
+	fn read_bijective_vlq() {
+		let data = b"\x00\x7f\x80\x00\xff\x7f\x80\x80\x00";
+		use crate::LERead;
+		let mut reader = &data[..];
+
+		let mut val: BijectiveVlq;
+		val = reader.read().unwrap();
+		assert_eq!(val, BijectiveVlq(0));
+		val = reader.read().unwrap();
+		assert_eq!(val, BijectiveVlq(127));
+		val = reader.read().unwrap();
+		assert_eq!(val, BijectiveVlq(128));
+		val = reader.read().unwrap();
+		assert_eq!(val, BijectiveVlq(16511));
+		val = reader.read().unwrap();
+		assert_eq!(val, BijectiveVlq(16512));
+		assert!(reader.is_empty());
+	}
+
+	#[test]
+	fn write_bijective_vlq() {
+		let data = b"\x00\x7f\x80\x00\xff\x7f\x80\x80\x00";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(BijectiveVlq(0)).unwrap();
+		writer.write(BijectiveVlq(127)).unwrap();
+		writer.write(BijectiveVlq(128)).unwrap();
+		writer.write(BijectiveVlq(16511)).unwrap();
+		writer.write(BijectiveVlq(16512)).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn read_bijective_vlq_overflow() {
+		use crate::LERead;
+		let mut reader = &b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\x00"[..];
+		let err = reader.read::<BijectiveVlq>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+	}
+
+	#[test]
+	fn read_sqlite_varint() {
+		let data = b"\x00\x7f\x81\x00\xff\xff\xff\xff\xff\xff\xff\x7f\x80\xc0\x80\x80\x80\x80\x80\x80\x00\xff\xff\xff\xff\xff\xff\xff\xff\xff";
+		use crate::LERead;
+		let mut reader = &data[..];
+
+		let mut val: SqliteVarint;
+		val = reader.read().unwrap();
+		assert_eq!(val, SqliteVarint(0));
+		val = reader.read().unwrap();
+		assert_eq!(val, SqliteVarint(0x7f));
+		val = reader.read().unwrap();
+		assert_eq!(val, SqliteVarint(0x80));
+		val = reader.read().unwrap();
+		assert_eq!(val, SqliteVarint(0x00ff_ffff_ffff_ffff));
+		val = reader.read().unwrap();
+		assert_eq!(val, SqliteVarint(0x0100_0000_0000_0000));
+		val = reader.read().unwrap();
+		assert_eq!(val, SqliteVarint(u64::MAX));
+		assert!(reader.is_empty());
+	}
+
+	#[test]
+	fn write_sqlite_varint() {
+		let data = b"\x00\x7f\x81\x00\xff\xff\xff\xff\xff\xff\xff\x7f\x80\xc0\x80\x80\x80\x80\x80\x80\x00\xff\xff\xff\xff\xff\xff\xff\xff\xff";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(SqliteVarint(0)).unwrap();
+		writer.write(SqliteVarint(0x7f)).unwrap();
+		writer.write(SqliteVarint(0x80)).unwrap();
+		writer.write(SqliteVarint(0x00ff_ffff_ffff_ffff)).unwrap();
+		writer.write(SqliteVarint(0x0100_0000_0000_0000)).unwrap();
+		writer.write(SqliteVarint(u64::MAX)).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn read_sqlite_varint_overlong() {
+		use crate::LERead;
+		let mut reader = &b"\x80\x01"[..];
+		let err = reader.read::<SqliteVarint>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+		let mut reader = &b"\x80\x80\x80\x80\x80\x80\x80\x80\x01"[..];
+		let err = reader.read::<SqliteVarint>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+	}
+
+	#[test]
+	fn read_compact_size() {
+		let data = b"\xfc\xfd\xfd\x00\xfd\xff\xff\xfe\x00\x00\x01\x00\xff\x00\x00\x00\x00\x01\x00\x00\x00";
+		use crate::LERead;
+		let mut reader = &data[..];
+
+		let mut val: CompactSize;
+		val = reader.read().unwrap();
+		assert_eq!(val, CompactSize(0xfc));
+		val = reader.read().unwrap();
+		assert_eq!(val, CompactSize(0xfd));
+		val = reader.read().unwrap();
+		assert_eq!(val, CompactSize(0xffff));
+		val = reader.read().unwrap();
+		assert_eq!(val, CompactSize(0x1_0000));
+		val = reader.read().unwrap();
+		assert_eq!(val, CompactSize(0x1_0000_0000));
+		assert!(reader.is_empty());
+	}
+
+	#[test]
+	fn write_compact_size() {
+		let data = b"\xfc\xfd\xfd\x00\xfd\xff\xff\xfe\x00\x00\x01\x00\xff\x00\x00\x00\x00\x01\x00\x00\x00";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(CompactSize(0xfc)).unwrap();
+		writer.write(CompactSize(0xfd)).unwrap();
+		writer.write(CompactSize(0xffff)).unwrap();
+		writer.write(CompactSize(0x1_0000)).unwrap();
+		writer.write(CompactSize(0x1_0000_0000)).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn read_compact_size_overlong() {
+		use crate::LERead;
+		let mut reader = &b"\xfd\xfc\x00"[..];
+		let err = reader.read::<CompactSize>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+		let mut reader = &b"\xfe\xff\xff\x00\x00"[..];
+		let err = reader.read::<CompactSize>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+		let mut reader = &b"\xff\x00\x00\x00\x00\x00\x00\x00\x00"[..];
+		let err = reader.read::<CompactSize>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+	}
+
+	#[test]
+	fn read_compact_size_eof() {
+		use crate::LERead;
+		let mut reader = &b"\xfe\x00"[..];
+		let err = reader.read::<CompactSize>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+	}
+
+	#[test]
+	fn read_quic_varint() {
+		let data = b"\x25\x7b\xbd\x9d\x7f\x3e\x7d\xc2\x19\x7c\x5e\xff\x14\xe8\x8c";
+		use crate::LERead;
+		let mut reader = &data[..];
+
+		let mut val: QuicVarint;
+		val = reader.read().unwrap();
+		assert_eq!(val, QuicVarint(37));
+		val = reader.read().unwrap();
+		assert_eq!(val, QuicVarint(15293));
+		val = reader.read().unwrap();
+		assert_eq!(val, QuicVarint(494878333));
+		val = reader.read().unwrap();
+		assert_eq!(val, QuicVarint(151288809941952652));
+		assert!(reader.is_empty());
+	}
+
+	#[test]
+	fn write_quic_varint() {
+		let data = b"\x25\x7b\xbd\x9d\x7f\x3e\x7d\xc2\x19\x7c\x5e\xff\x14\xe8\x8c";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(QuicVarint(37)).unwrap();
+		writer.write(QuicVarint(15293)).unwrap();
+		writer.write(QuicVarint(494878333)).unwrap();
+		writer.write(QuicVarint(151288809941952652)).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn bijective_vlq_max() {
+		let data = b"\x80\xfe\xfe\xfe\xfe\xfe\xfe\xfe\xfe\x7f";
+		{
+			use crate::LEWrite;
+			let mut writer = vec![];
+			writer.write(BijectiveVlq(u64::MAX)).unwrap();
+			assert_eq!(writer, data);
+		}
+		{
+			use crate::LERead;
+			let mut reader = &data[..];
+			let val: BijectiveVlq = reader.read().unwrap();
+			assert_eq!(val, BijectiveVlq(u64::MAX));
+		}
+	}
+
+	#[test]
+	fn read_compact_size_be() {
+		let data = b"\xfd\x00\x01";
+		use crate::BERead;
+		let mut reader = &data[..];
+		let val: CompactSize = reader.read().unwrap();
+		assert_eq!(val, CompactSize(0x100));
+	}
+
+	#[test]
+	fn read_quic_varint_non_minimal() {
+		let data = b"\x40\x25";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let val: QuicVarint = reader.read().unwrap();
+		assert_eq!(val, QuicVarint(37));
+	}
+
+	#[test]
+	fn write_quic_varint_too_large() {
+		use crate::LEWrite;
+		let mut writer = vec![];
+		let err = writer.write(QuicVarint(1 << 62)).unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+	}
+
+	#[test]
+	fn read_len_types() {
+		use crate::LERead;
+		let mut reader = &b"\x02hi"[..];
+		let val: LenString<VarU32> = reader.read().unwrap();
+		assert_eq!(*val, "hi");
+		let mut reader = &b"\x02\x01\x02"[..];
+		let val: LenPrefixed<Vlq, Vec<u8>> = reader.read().unwrap();
+		assert_eq!(*val, vec![1, 2]);
+		let mut reader = &b"\x40\x02hi"[..];
+		let val: LenString<QuicVarint> = reader.read().unwrap();
+		assert_eq!(*val, "hi");
+	}
+
+	#[test]
+	fn write_len_types() {
+		let data = b"\x02hi\x02hi\x02hi\x02hi";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(LenString::<VarU32, _>::new("hi")).unwrap();
+		writer.write(LenString::<SqliteVarint, _>::new("hi")).unwrap();
+		writer.write(LenString::<BijectiveVlq, _>::new("hi")).unwrap();
+		writer.write(LenString::<CompactSize, _>::new("hi")).unwrap();
+		assert_eq!(writer, data);
+	}
+}
@@ -1,7 +1,5 @@
-use std::io::Write;
-use std::io::Result as Res;
-
-use crate::{BigEndian, Endianness, LittleEndian, Serialize};
+use crate::io::Result as Res;
+use crate::{BigEndian, ByteWrite, Endianness, LittleEndian, NativeEndian, Serialize};
 
 /**
 	Only necessary for custom (de-)serializations.
@@ -64,9 +62,23 @@ pub trait LEWrite: Sized {
 	fn write_le<S: Serialize<LittleEndian, Self>>(&mut self, ser: S) -> Res<()> { ser.serialize(self) }
 }
 
-impl<W: Write, E: Endianness> EWrite<E> for W {}
-impl<W: Write> BEWrite for W {}
-impl<W: Write> LEWrite for W {}
+/**
+	Use this to `write` in the **native** endianness of the target platform.
+
+	Wrapper for `EWrite<NativeEndian>`.
+
+	This exists solely to make `use` notation work. See `EWrite` for documentation. For network byte order, use `BEWrite`.
+*/
+pub trait NEWrite: Sized {
+	fn write   <S: Serialize<NativeEndian, Self>>(&mut self, ser: S) -> Res<()> { ser.serialize(self) }
+	fn write_be<S: Serialize<BigEndian,    Self>>(&mut self, ser: S) -> Res<()> { ser.serialize(self) }
+	fn write_le<S: Serialize<LittleEndian, Self>>(&mut self, ser: S) -> Res<()> { ser.serialize(self) }
+}
+
+impl<W: ByteWrite, E: Endianness> EWrite<E> for W {}
+impl<W: ByteWrite> BEWrite for W {}
+impl<W: ByteWrite> LEWrite for W {}
+impl<W: ByteWrite> NEWrite for W {}
 
 #[cfg(test)]
 mod tests {
@@ -87,4 +99,12 @@ mod tests {
 		writer.write_le(0xadbau16).unwrap();
 		assert_eq!(&writer[..], DATA);
 	}
+
+	#[test]
+	fn write_native() {
+		use crate::NEWrite;
+		let mut writer = vec![];
+		writer.write(u16::from_ne_bytes([0xba, 0xad])).unwrap();
+		assert_eq!(writer, DATA);
+	}
 }
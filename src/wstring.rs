@@ -0,0 +1,387 @@
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::ops::{Deref, DerefMut};
+
+use crate::io;
+use crate::io::Result as Res;
+use crate::{Deserialize, Endianness, ERead, EWrite, LenPrefixed, Serialize};
+use crate::len_prefixed::{read_len, write_len};
+
+/**
+	A string stored as UTF-16 code units, preceded by its length in code units.
+
+	The code units are (de-)serialized as `u16` in the stream's endianness. The length is (de-)serialized as `L`, with the same rules as for other [`LenPrefixed`] collections.
+
+	Reading returns an `InvalidData` error if the string contains unpaired surrogates, unless lossy mode is enabled by using `WString<true>` as the string type, in which case they are replaced with U+FFFD REPLACEMENT CHARACTER.
+
+	## Examples
+
+	```
+	use endio::{LERead, LEWrite, LenWString, WString};
+
+	let mut reader = &b"\x02\x00h\x00i\x00"[..];
+	let val: LenWString<u16> = reader.read().unwrap();
+	assert_eq!(**val, "hi");
+
+	let mut writer = vec![];
+	writer.write(&val).unwrap();
+	assert_eq!(writer, b"\x02\x00h\x00i\x00");
+
+	let mut reader = &b"\x01\x00\x00\xd8"[..];
+	assert!(reader.read::<LenWString<u16>>().is_err());
+	let mut reader = &b"\x01\x00\x00\xd8"[..];
+	let val: LenWString<u16, WString<true>> = reader.read().unwrap();
+	assert_eq!(**val, "\u{fffd}");
+	```
+*/
+pub type LenWString<L, S = WString> = LenPrefixed<L, S>;
+
+/**
+	A string stored as UTF-16 code units.
+
+	Only used as the content of a [`LenWString`], see there for details. Set `LOSSY` to `true` to replace unpaired surrogates instead of returning an error when reading.
+*/
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WString<const LOSSY: bool = false>(pub String);
+
+/**
+	A string stored as UTF-16 code units, terminated by a NUL code unit.
+
+	The code units are (de-)serialized as `u16` in the stream's endianness. Reading stops at the first NUL code unit, which is consumed but not included in the string. Writing appends a NUL code unit, and returns an `InvalidInput` error if the string itself contains one.
+
+	Reading returns an `InvalidData` error if the string contains unpaired surrogates, unless `LOSSY` is `true`, in which case they are replaced with U+FFFD REPLACEMENT CHARACTER.
+
+	## Examples
+
+	```
+	use endio::{BERead, BEWrite, NulWString};
+
+	let mut reader = &b"\x00h\x00i\x00\x00"[..];
+	let val: NulWString = reader.read().unwrap();
+	assert_eq!(*val, "hi");
+
+	let mut writer = vec![];
+	writer.write(&val).unwrap();
+	assert_eq!(writer, b"\x00h\x00i\x00\x00");
+	```
+*/
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NulWString<const LOSSY: bool = false>(pub String);
+
+/**
+	A string stored as UTF-16 code units in a fixed-width field of `N` code units, padded with NUL code units.
+
+	The code units are (de-)serialized as `u16` in the stream's endianness. Reading always consumes `N` code units, and the string ends at the first NUL code unit, if any. Writing pads the string with NUL code units up to `N` code units, and returns an `InvalidInput` error if the string is longer than that or contains a NUL code unit.
+
+	Reading returns an `InvalidData` error if the string contains unpaired surrogates, unless `LOSSY` is `true`, in which case they are replaced with U+FFFD REPLACEMENT CHARACTER.
+
+	## Examples
+
+	```
+	use endio::{FixedWString, LERead, LEWrite};
+
+	let mut reader = &b"h\x00i\x00\x00\x00\x00\x00"[..];
+	let val: FixedWString<4> = reader.read().unwrap();
+	assert_eq!(*val, "hi");
+
+	let mut writer = vec![];
+	writer.write(&val).unwrap();
+	assert_eq!(writer, b"h\x00i\x00\x00\x00\x00\x00");
+	```
+*/
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FixedWString<const N: usize, const LOSSY: bool = false>(pub String);
+
+fn from_utf16(units: &[u16], lossy: bool) -> Res<String> {
+	if lossy {
+		Ok(String::from_utf16_lossy(units))
+	} else {
+		String::from_utf16(units).map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "string was not valid UTF-16"))
+	}
+}
+
+fn check_no_nul(string: &str) -> Res<()> {
+	if string.contains('\0') {
+		return Err(io::Error::new(io::ErrorKind::InvalidInput, "string contains a NUL code unit"));
+	}
+	Ok(())
+}
+
+/// Reads a length of type `L`, then reads that many UTF-16 code units.
+impl<E: Endianness, R: ERead<E>, L, const LOSSY: bool> Deserialize<E, R> for LenPrefixed<L, WString<LOSSY>>
+	where L: Deserialize<E, R>,
+	      usize: TryFrom<L>,
+	      u16: Deserialize<E, R> {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let len = read_len::<E, R, L>(reader)?;
+		let units = u16::deserialize_vec(reader, len)?;
+		Ok(Self::new(WString(from_utf16(&units, LOSSY)?)))
+	}
+}
+
+/// Writes the length of the string in UTF-16 code units as `L`, then writes the code units.
+impl<E: Endianness, W: EWrite<E>, L, const LOSSY: bool> Serialize<E, W> for &LenPrefixed<L, WString<LOSSY>>
+	where L: Serialize<E, W> + TryFrom<usize>,
+	      for<'a> &'a u16: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		let units: Vec<u16> = self.encode_utf16().collect();
+		write_len::<E, W, L>(writer, units.len())?;
+		<&u16>::serialize_slice(&units, writer)
+	}
+}
+
+/// Reads UTF-16 code units up to and including a NUL code unit.
+impl<E: Endianness, R: ERead<E>, const LOSSY: bool> Deserialize<E, R> for NulWString<LOSSY> where u16: Deserialize<E, R> {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let mut units = vec![];
+		loop {
+			let unit: u16 = reader.read()?;
+			if unit == 0 {
+				break;
+			}
+			units.push(unit);
+		}
+		Ok(Self(from_utf16(&units, LOSSY)?))
+	}
+}
+
+/// Writes the string as UTF-16 code units, followed by a NUL code unit.
+impl<E: Endianness, W: EWrite<E>, const LOSSY: bool> Serialize<E, W> for &NulWString<LOSSY> where for<'a> &'a u16: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		check_no_nul(&self.0)?;
+		let mut units: Vec<u16> = self.0.encode_utf16().collect();
+		units.push(0);
+		<&u16>::serialize_slice(&units, writer)
+	}
+}
+
+/// Reads `N` UTF-16 code units, and uses the code units up to the first NUL code unit as the string.
+impl<E: Endianness, R: ERead<E>, const N: usize, const LOSSY: bool> Deserialize<E, R> for FixedWString<N, LOSSY> where u16: Deserialize<E, R> {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let units: [u16; N] = reader.read()?;
+		let len = units.iter().position(|x| *x == 0).unwrap_or(N);
+		Ok(Self(from_utf16(&units[..len], LOSSY)?))
+	}
+}
+
+/// Writes the string as UTF-16 code units, padded with NUL code units to `N` code units.
+impl<E: Endianness, W: EWrite<E>, const N: usize, const LOSSY: bool> Serialize<E, W> for &FixedWString<N, LOSSY> where for<'a> &'a u16: Serialize<E, W> {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		let mut units: Vec<u16> = self.0.encode_utf16().collect();
+		if units.len() > N {
+			return Err(io::Error::new(io::ErrorKind::InvalidInput, "string is longer than its fixed-width field"));
+		}
+		check_no_nul(&self.0)?;
+		units.resize(N, 0);
+		<&u16>::serialize_slice(&units, writer)
+	}
+}
+
+macro_rules! impl_wstring {
+	($t:ident, $($param:ident)*) => {
+		impl<$(const $param: usize,)* const LOSSY: bool> From<String> for $t<$($param,)* LOSSY> {
+			fn from(string: String) -> Self {
+				Self(string)
+			}
+		}
+
+		impl<$(const $param: usize,)* const LOSSY: bool> From<&str> for $t<$($param,)* LOSSY> {
+			fn from(string: &str) -> Self {
+				Self(string.into())
+			}
+		}
+
+		impl<$(const $param: usize,)* const LOSSY: bool> Deref for $t<$($param,)* LOSSY> {
+			type Target = String;
+
+			fn deref(&self) -> &String {
+				&self.0
+			}
+		}
+
+		impl<$(const $param: usize,)* const LOSSY: bool> DerefMut for $t<$($param,)* LOSSY> {
+			fn deref_mut(&mut self) -> &mut String {
+				&mut self.0
+			}
+		}
+	}
+}
+
+impl_wstring!(WString,);
+impl_wstring!(NulWString,);
+impl_wstring!(FixedWString, N);
+
+#[cfg(test)]
+mod tests {
+	use crate::io;
+	use crate::{FixedWString, LenWString, NulWString, WString};
+
+	#[test]
+	fn read_len_wstring() {
+		let data = b"\x02\x00h\x00i";
+		let mut val: LenWString<u8>;
+		{
+			use crate::BERead;
+			let mut reader = &data[..];
+			val = reader.read().unwrap();
+			assert_eq!(**val, "hi");
+		}
+		{
+			use crate::LERead;
+			let mut reader = &data[..];
+			val = reader.read().unwrap();
+			assert_eq!(**val, "\u{6800}\u{6900}");
+		}
+	}
+
+	#[test]
+	fn read_len_wstring_surrogate_pair() {
+		let data = b"\x02\x3d\xd8\x00\xde";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let val: LenWString<u8> = reader.read().unwrap();
+		assert_eq!(**val, "\u{1f600}");
+	}
+
+	#[test]
+	fn read_len_wstring_unpaired_surrogate() {
+		let data = b"\x02\x00\xdeh\x00";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let err = reader.read::<LenWString<u8>>().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+	}
+
+	#[test]
+	fn read_len_wstring_lossy() {
+		let data = b"\x02\x00\xdeh\x00";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let val: LenWString<u8, WString<true>> = reader.read().unwrap();
+		assert_eq!(**val, "\u{fffd}h");
+	}
+
+	#[test]
+	fn write_len_wstring() {
+		let data = b"\x00\x00\x00\x02\xd8\x3d\xde\x00";
+		use crate::BEWrite;
+		let mut writer = vec![];
+		writer.write(&LenWString::<u32>::new("\u{1f600}".into())).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	#[cfg(feature="std")]
+	fn len_wstring_single_call() {
+		struct Counting(Vec<u8>, usize);
+
+		impl std::io::Read for Counting {
+			fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+				self.1 += 1;
+				let len = std::io::Read::read(&mut &self.0[..], buf)?;
+				self.0.drain(..len);
+				Ok(len)
+			}
+		}
+
+		impl std::io::Write for Counting {
+			fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+				self.1 += 1;
+				std::io::Write::write(&mut self.0, buf)
+			}
+
+			fn flush(&mut self) -> io::Result<()> {
+				Ok(())
+			}
+		}
+
+		let string = "hi".repeat(500);
+		{
+			use crate::BEWrite;
+			let mut writer = Counting(vec![], 0);
+			writer.write(&LenWString::<u16>::new(string.as_str().into())).unwrap();
+			assert_eq!(writer.0.len(), 2002);
+			assert_eq!(writer.1, 2);
+			use crate::BERead;
+			let mut reader = Counting(writer.0, 0);
+			let val: LenWString<u16> = reader.read().unwrap();
+			assert_eq!(**val, string);
+			assert_eq!(reader.1, 2);
+		}
+		{
+			use crate::LEWrite;
+			let mut writer = Counting(vec![], 0);
+			writer.write(&FixedWString::<1000>::from("hi")).unwrap();
+			assert_eq!(&writer.0[..6], b"h\x00i\x00\x00\x00");
+			assert_eq!(writer.1, 1);
+		}
+	}
+
+	#[test]
+	fn read_nul_wstring() {
+		let data = b"h\x00i\x00\x00\x00r\x00";
+		use crate::LERead;
+		let mut reader = &data[..];
+		let val: NulWString = reader.read().unwrap();
+		assert_eq!(*val, "hi");
+		assert_eq!(reader, b"r\x00");
+	}
+
+	#[test]
+	fn read_nul_wstring_lossy() {
+		let data = b"\xd8\x00\x00\x00";
+		use crate::BERead;
+		let mut reader = &data[..];
+		assert_eq!(reader.read::<NulWString>().unwrap_err().kind(), io::ErrorKind::InvalidData);
+		let mut reader = &data[..];
+		let val: NulWString<true> = reader.read().unwrap();
+		assert_eq!(*val, "\u{fffd}");
+	}
+
+	#[test]
+	fn write_nul_wstring() {
+		let data = b"h\x00i\x00\x00\x00";
+		use crate::LEWrite;
+		let mut writer = vec![];
+		writer.write(&NulWString::<false>::from("hi")).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn write_nul_wstring_interior_nul() {
+		use crate::LEWrite;
+		let mut writer = vec![];
+		let err = writer.write(&NulWString::<false>::from("h\0i")).unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+	}
+
+	#[test]
+	fn read_fixed_wstring() {
+		let data = b"\x00h\x00i\x00\x00\x00\x00";
+		use crate::BERead;
+		let mut reader = &data[..];
+		let val: FixedWString<4> = reader.read().unwrap();
+		assert_eq!(*val, "hi");
+		assert!(reader.is_empty());
+	}
+
+	#[test]
+	fn write_fixed_wstring() {
+		let data = b"\x00h\x00i\x00\x00";
+		use crate::BEWrite;
+		let mut writer = vec![];
+		writer.write(&FixedWString::<3>::from("hi")).unwrap();
+		assert_eq!(writer, data);
+	}
+
+	#[test]
+	fn write_fixed_wstring_too_long() {
+		use crate::BEWrite;
+		let mut writer = vec![];
+		let err = writer.write(&FixedWString::<1>::from("\u{1f600}")).unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+		assert!(writer.is_empty());
+	}
+}